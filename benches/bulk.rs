@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use imei_info::{validate_bulk, Imei, RecordFormat, Tac};
+
+/// Build a newline-delimited buffer of IMEIs, roughly one in ten of which has a bad check digit.
+fn sample_buffer(count: u64) -> Vec<u8> {
+    let tac = Tac::from_str("35674108").unwrap();
+    let mut buffer = Vec::new();
+    for i in 0..count {
+        let mut imei = Imei::from(tac.clone());
+        for (digit, position) in imei.digits[8..14].iter_mut().zip((0..6).rev()) {
+            *digit = ((i / 10u64.pow(position)) % 10) as u8;
+        }
+
+        imei.digits[14] = imei_info::luhn_checksum(imei.without_check_digit());
+        if i % 10 == 0 {
+            imei.digits[14] = (imei.digits[14] + 1) % 10;
+        }
+
+        buffer.extend_from_slice(imei.to_string().as_bytes());
+        buffer.push(b'\n');
+    }
+
+    buffer
+}
+
+fn bulk_validation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk_validation");
+    for count in [10_000, 1_000_000] {
+        let buffer = sample_buffer(count);
+        group.throughput(Throughput::Elements(count));
+
+        group.bench_with_input(BenchmarkId::new("from_str", count), &buffer, |b, buffer| {
+            b.iter(|| {
+                std::str::from_utf8(buffer)
+                    .unwrap()
+                    .lines()
+                    .filter(|line| Imei::from_str(line).is_ok())
+                    .count()
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("validate_bulk", count),
+            &buffer,
+            |b, buffer| b.iter(|| validate_bulk(buffer, RecordFormat::Lines).summary().valid),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bulk_validation);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    ffi::build();
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    use std::env;
+    use std::path::PathBuf;
+
+    /// Generate the C header from `src/ffi.rs` and compile the C test harness against the checked-in copy of it.
+    /// The `ffi` integration test checks that both headers are identical, so the checked-in header cannot go stale.
+    pub(super) fn build() {
+        let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+        let generated_header = out_dir.join("imei_info.h");
+
+        cbindgen::Builder::new()
+            .with_crate(&crate_dir)
+            .with_config(cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap())
+            .generate()
+            .expect("unable to generate C header for the `ffi` module")
+            .write_to_file(&generated_header);
+
+        cc::Build::new()
+            .file(crate_dir.join("tests/c/ffi_harness.c"))
+            .include(crate_dir.join("include"))
+            .warnings_into_errors(true)
+            .cargo_metadata(false)
+            .compile("imei_info_ffi_harness");
+
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+        println!(
+            "cargo:rustc-env=IMEI_INFO_GENERATED_HEADER={}",
+            generated_header.display()
+        );
+        for path in [
+            "build.rs",
+            "cbindgen.toml",
+            "src",
+            "include/imei_info.h",
+            "tests/c/ffi_harness.c",
+        ] {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+}
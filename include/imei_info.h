@@ -0,0 +1,202 @@
+#ifndef IMEI_INFO_H
+#define IMEI_INFO_H
+
+/* This file is generated by cbindgen from `src/ffi.rs`. Do not edit it manually. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The outcome of an FFI call. Every variant other than `Ok` corresponds to an error, and any
+// output parameters are left untouched when an error is returned.
+typedef enum ImeiInfoStatus {
+  IMEI_INFO_STATUS_OK = 0,
+  IMEI_INFO_STATUS_NULL_POINTER = 1,
+  IMEI_INFO_STATUS_INCORRECT_LENGTH = 2,
+  IMEI_INFO_STATUS_CANNOT_PARSE_DIGITS = 3,
+  IMEI_INFO_STATUS_CHECKSUM_DOES_NOT_MATCH = 4,
+  IMEI_INFO_STATUS_VALUE_OUT_OF_RANGE = 5,
+  IMEI_INFO_STATUS_BUFFER_TOO_SMALL = 6,
+} ImeiInfoStatus;
+
+// An IMEISV number (IMEI with software version), represented using an array of digits to prevent
+// integer over/underflow or leading-zero truncation.
+//
+// The IMEISV replaces the IMEI check digit with a two-digit software version number (SVN), so it
+// has no checksum of its own.
+//
+// This type implements `Into` for `i64`, `u64`, `i128`, and `u128`.
+typedef struct ImeiInfoImeiSv {
+  uint8_t digits[16];
+} ImeiInfoImeiSv;
+
+// An IMEI number, represented using an array of digits to prevent integer over/underflow or
+// leading-zero truncation.
+//
+// This type implements `Into` for `i64`, `u64`, `i128`, and `u128`. It does not implement `Into`
+// for 32-bit or platform-dependent integer types because the IMEI is 15 digits and typically
+// cannot be stored in anything less than a 64-bit integer.
+typedef struct ImeiInfoImei {
+  uint8_t digits[15];
+} ImeiInfoImei;
+
+// A TAC number, represented using an array of digits to prevent integer over/underflow or
+// leading-zero truncation.
+//
+// This type implements `Into` for `i32`, `u32`, `i64`, `u64`, `i128`, `u128`, `isize`, and `usize`.
+typedef struct ImeiInfoTac {
+  uint8_t digits[8];
+} ImeiInfoTac;
+
+// Get a static, NUL-terminated description of a status code. The returned string must not be freed.
+// The status is taken as an integer so that any value from C is safe to pass, and unknown values
+// are described as such.
+const char *imei_info_status_message(int status);
+
+// Compute the Luhn check digit for `len` digits (each in the range 0 to 9, not ASCII characters)
+// and store it in `out`.
+//
+// # Safety
+// `digits` must point to `len` readable bytes and `out` must point to a writable byte.
+enum ImeiInfoStatus imei_info_luhn_check_digit(const uint8_t *digits, size_t len, uint8_t *out);
+
+// Compute the check digit for the first 14 digits of an IMEI, given as ASCII characters, and
+// store it in `out` as a number in the range 0 to 9.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable byte.
+enum ImeiInfoStatus imei_info_imei_compute_check_digit(const char *input, size_t len, uint8_t *out);
+
+// Convert an IMEISV to the IMEI of the same device, replacing the SVN with a computed check digit.
+//
+// # Safety
+// `imeisv` must point to a valid `ImeiInfoImeiSv` and `out` must point to a writable `ImeiInfoImei`.
+enum ImeiInfoStatus imei_info_imeisv_to_imei(const struct ImeiInfoImeiSv *imeisv,
+                                             struct ImeiInfoImei *out);
+
+// Extract the TAC from an IMEI.
+//
+// # Safety
+// `imei` must point to a valid `ImeiInfoImei` and `out` must point to a writable `ImeiInfoTac`.
+enum ImeiInfoStatus imei_info_imei_to_tac(const struct ImeiInfoImei *imei, struct ImeiInfoTac *out);
+
+// Strictly parse an IMEI consisting of exactly the required number of ASCII digits and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImei`.
+enum ImeiInfoStatus imei_info_imei_parse(const char *input,
+                                         size_t len,
+                                         struct ImeiInfoImei *out);
+
+// Parse an IMEI which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImei`.
+enum ImeiInfoStatus imei_info_imei_parse_lenient(const char *input,
+                                                 size_t len,
+                                                 struct ImeiInfoImei *out);
+
+// Check whether the input is a strictly formatted, valid IMEI without storing the result.
+//
+// # Safety
+// `input` must point to `len` readable bytes.
+enum ImeiInfoStatus imei_info_imei_validate(const char *input, size_t len);
+
+// Leniently parse an IMEI and write its canonical form (digits only) into `out` as a NUL-terminated string.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_imei_normalize(const char *input,
+                                             size_t len,
+                                             char *out,
+                                             size_t out_len);
+
+// Write the digits of an IMEI into `out` as a NUL-terminated string. If any digit is greater than 9,
+// `ValueOutOfRange` is returned and nothing is written.
+//
+// # Safety
+// `value` must point to a valid `ImeiInfoImei` and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_imei_format(const struct ImeiInfoImei *value,
+                                          char *out,
+                                          size_t out_len);
+
+// Strictly parse an IMEISV consisting of exactly the required number of ASCII digits and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImeiSv`.
+enum ImeiInfoStatus imei_info_imeisv_parse(const char *input,
+                                           size_t len,
+                                           struct ImeiInfoImeiSv *out);
+
+// Parse an IMEISV which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImeiSv`.
+enum ImeiInfoStatus imei_info_imeisv_parse_lenient(const char *input,
+                                                   size_t len,
+                                                   struct ImeiInfoImeiSv *out);
+
+// Check whether the input is a strictly formatted, valid IMEISV without storing the result.
+//
+// # Safety
+// `input` must point to `len` readable bytes.
+enum ImeiInfoStatus imei_info_imeisv_validate(const char *input, size_t len);
+
+// Leniently parse an IMEISV and write its canonical form (digits only) into `out` as a NUL-terminated string.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_imeisv_normalize(const char *input,
+                                               size_t len,
+                                               char *out,
+                                               size_t out_len);
+
+// Write the digits of an IMEISV into `out` as a NUL-terminated string. If any digit is greater than 9,
+// `ValueOutOfRange` is returned and nothing is written.
+//
+// # Safety
+// `value` must point to a valid `ImeiInfoImeiSv` and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_imeisv_format(const struct ImeiInfoImeiSv *value,
+                                            char *out,
+                                            size_t out_len);
+
+// Strictly parse a TAC consisting of exactly the required number of ASCII digits and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoTac`.
+enum ImeiInfoStatus imei_info_tac_parse(const char *input,
+                                        size_t len,
+                                        struct ImeiInfoTac *out);
+
+// Parse a TAC which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoTac`.
+enum ImeiInfoStatus imei_info_tac_parse_lenient(const char *input,
+                                                size_t len,
+                                                struct ImeiInfoTac *out);
+
+// Check whether the input is a strictly formatted, valid TAC without storing the result.
+//
+// # Safety
+// `input` must point to `len` readable bytes.
+enum ImeiInfoStatus imei_info_tac_validate(const char *input, size_t len);
+
+// Leniently parse a TAC and write its canonical form (digits only) into `out` as a NUL-terminated string.
+//
+// # Safety
+// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_tac_normalize(const char *input,
+                                            size_t len,
+                                            char *out,
+                                            size_t out_len);
+
+// Write the digits of a TAC into `out` as a NUL-terminated string. If any digit is greater than 9,
+// `ValueOutOfRange` is returned and nothing is written.
+//
+// # Safety
+// `value` must point to a valid `ImeiInfoTac` and `out` must point to `out_len` writable bytes.
+enum ImeiInfoStatus imei_info_tac_format(const struct ImeiInfoTac *value,
+                                         char *out,
+                                         size_t out_len);
+
+#endif  /* IMEI_INFO_H */
@@ -0,0 +1,309 @@
+//! Python bindings for the `imei-info` crate, built as the `imei_info` extension module with `maturin`.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use tokio::runtime::{Builder, Runtime};
+
+use imei_info::{Imei, ImeiWrapperError, PhoneInfo, Tac};
+
+create_exception!(
+    imei_info,
+    ServiceCheckError,
+    PyException,
+    "Raised when a request to the IMEI.info API fails."
+);
+create_exception!(
+    imei_info,
+    RequestPendingError,
+    ServiceCheckError,
+    "Raised when the IMEI.info API has not resolved a request yet. The arguments are the message, the history ID, and the ULID of the request."
+);
+
+/// An IMEI number. It can be constructed from a string of exactly 15 digits or from an integer,
+/// and the check digit is always validated.
+#[pyclass(name = "Imei", module = "imei_info", frozen, eq, hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PyImei(Imei);
+
+/// A TAC number. It can be constructed from a string of exactly 8 digits or from an integer.
+#[pyclass(name = "Tac", module = "imei_info", frozen, eq, hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PyTac(Tac);
+
+/// The basic information about a phone: its IMEIs, make, and model.
+#[pyclass(name = "PhoneInfo", module = "imei_info", frozen, eq)]
+#[derive(Clone, PartialEq)]
+struct PyPhoneInfo(PhoneInfo);
+
+#[pymethods]
+impl PyImei {
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(imei_from_object(value, false)?))
+    }
+
+    /// Parse an IMEI which may contain whitespace, dashes, dots, or slashes as separators.
+    #[staticmethod]
+    fn parse_lenient(value: &str) -> PyResult<Self> {
+        Ok(Self(Imei::parse_lenient(value).map_err(value_error)?))
+    }
+
+    #[getter]
+    fn digits(&self) -> Vec<u8> {
+        self.0.digits.to_vec()
+    }
+
+    #[getter]
+    fn reporting_body(&self) -> String {
+        digits_to_string(self.0.reporting_body())
+    }
+
+    #[getter]
+    fn model_identifier(&self) -> String {
+        digits_to_string(self.0.model_identifier())
+    }
+
+    #[getter]
+    fn type_allocation_code(&self) -> String {
+        digits_to_string(self.0.type_allocation_code())
+    }
+
+    #[getter]
+    fn serial_number(&self) -> String {
+        digits_to_string(self.0.serial_number())
+    }
+
+    #[getter]
+    fn check_digit(&self) -> u8 {
+        self.0.check_digit()
+    }
+
+    /// Get the TAC of this IMEI.
+    fn tac(&self) -> PyTac {
+        PyTac(self.0.clone().into())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Imei('{}')", self.0)
+    }
+
+    fn __int__(&self) -> u64 {
+        (&self.0).into()
+    }
+}
+
+#[pymethods]
+impl PyTac {
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value) = value.extract::<PyRef<'_, PyImei>>() {
+            return Ok(Self(value.0.clone().into()));
+        }
+
+        let tac = if let Ok(value) = value.extract::<&str>() {
+            Tac::from_str(value)
+        } else if let Ok(value) = value.extract::<i128>() {
+            Tac::try_from(value)
+        } else {
+            return Err(type_error(value));
+        };
+
+        Ok(Self(tac.map_err(value_error)?))
+    }
+
+    /// Parse a TAC which may contain whitespace, dashes, dots, or slashes as separators.
+    #[staticmethod]
+    fn parse_lenient(value: &str) -> PyResult<Self> {
+        Ok(Self(Tac::parse_lenient(value).map_err(value_error)?))
+    }
+
+    #[getter]
+    fn digits(&self) -> Vec<u8> {
+        self.0.digits.to_vec()
+    }
+
+    #[getter]
+    fn reporting_body(&self) -> String {
+        digits_to_string(self.0.reporting_body())
+    }
+
+    #[getter]
+    fn model_identifier(&self) -> String {
+        digits_to_string(self.0.model_identifier())
+    }
+
+    /// Generate a generic IMEI for this TAC, with a zeroed serial number and a valid check digit.
+    fn to_imei(&self) -> PyImei {
+        PyImei(self.0.clone().into())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tac('{}')", self.0)
+    }
+
+    fn __int__(&self) -> u64 {
+        (&self.0).into()
+    }
+}
+
+#[pymethods]
+impl PyPhoneInfo {
+    #[getter]
+    fn imei(&self) -> PyImei {
+        PyImei(self.0.imeis.primary.clone())
+    }
+
+    /// The second IMEI of a dual-SIM device, or `None`.
+    #[getter]
+    fn imei2(&self) -> Option<PyImei> {
+        self.0.imeis.secondary.clone().map(PyImei)
+    }
+
+    #[getter]
+    fn manufacturer(&self) -> &str {
+        &self.0.manufacturer
+    }
+
+    #[getter]
+    fn model(&self) -> &str {
+        &self.0.model
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PhoneInfo(imei=Imei('{}'), manufacturer='{}', model='{}')",
+            self.0.imeis.primary, self.0.manufacturer, self.0.model
+        )
+    }
+}
+
+/// Check whether a single value is a valid IMEI.
+#[pyfunction]
+#[pyo3(signature = (value, lenient = false))]
+fn is_valid_imei(value: &Bound<'_, PyAny>, lenient: bool) -> bool {
+    imei_from_object(value, lenient).is_ok()
+}
+
+/// Check whether each value of an iterable (such as a list or a pandas `Series`) is a valid IMEI.
+/// Values which are neither strings nor integers (such as `None` or `NaN`) are reported as invalid.
+#[pyfunction]
+#[pyo3(signature = (values, lenient = false))]
+fn validate_imeis(values: &Bound<'_, PyAny>, lenient: bool) -> PyResult<Vec<bool>> {
+    values
+        .try_iter()?
+        .map(|value| Ok(imei_from_object(&value?, lenient).is_ok()))
+        .collect()
+}
+
+/// Get the basic information about a device (make and model) using its IMEI.
+/// This blocks until the request completes, but releases the GIL while waiting.
+#[pyfunction]
+fn get_imei_info(py: Python<'_>, api_key: &str, imei: &Bound<'_, PyAny>) -> PyResult<PyPhoneInfo> {
+    let imei = imei_from_object(imei, false)?;
+    py.allow_threads(|| {
+        runtime()
+            .block_on(imei_info::get_imei_info(api_key, imei))
+            .map(PyPhoneInfo)
+            .map_err(service_check_error)
+    })
+}
+
+/// Get the basic information about a device (make and model) using its TAC.
+/// This blocks until the request completes, but releases the GIL while waiting.
+#[pyfunction]
+fn get_tac_info(py: Python<'_>, api_key: &str, tac: &Bound<'_, PyAny>) -> PyResult<PyPhoneInfo> {
+    let tac = PyTac::new(tac)?.0;
+    py.allow_threads(|| {
+        runtime()
+            .block_on(imei_info::get_tac_info(api_key, tac))
+            .map(PyPhoneInfo)
+            .map_err(service_check_error)
+    })
+}
+
+fn imei_from_object(value: &Bound<'_, PyAny>, lenient: bool) -> PyResult<Imei> {
+    if let Ok(value) = value.extract::<PyRef<'_, PyImei>>() {
+        return Ok(value.0.clone());
+    }
+
+    let imei = if let Ok(value) = value.extract::<&str>() {
+        if lenient {
+            Imei::parse_lenient(value)
+        } else {
+            Imei::from_str(value)
+        }
+    } else if let Ok(value) = value.extract::<i128>() {
+        Imei::try_from(value)
+    } else {
+        return Err(type_error(value));
+    };
+
+    imei.map_err(value_error)
+}
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start the Tokio runtime")
+    })
+}
+
+fn digits_to_string(digits: &[u8]) -> String {
+    digits.iter().map(|d| char::from(b'0' + d)).collect()
+}
+
+fn value_error(error: ImeiWrapperError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+fn type_error(value: &Bound<'_, PyAny>) -> PyErr {
+    let type_name = value
+        .get_type()
+        .name()
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| "unknown".to_owned());
+    PyTypeError::new_err(format!("expected a string or an integer, got {type_name}"))
+}
+
+fn service_check_error(error: imei_info::ServiceCheckError) -> PyErr {
+    match error {
+        imei_info::ServiceCheckError::RequestPending {
+            ref history_id,
+            ref ulid,
+        } => RequestPendingError::new_err((error.to_string(), history_id.clone(), ulid.clone())),
+        error => ServiceCheckError::new_err(error.to_string()),
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "imei_info")]
+fn imei_info_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyImei>()?;
+    m.add_class::<PyTac>()?;
+    m.add_class::<PyPhoneInfo>()?;
+    m.add_function(wrap_pyfunction!(is_valid_imei, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_imeis, m)?)?;
+    m.add_function(wrap_pyfunction!(get_imei_info, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tac_info, m)?)?;
+    m.add("ServiceCheckError", m.py().get_type::<ServiceCheckError>())?;
+    m.add(
+        "RequestPendingError",
+        m.py().get_type::<RequestPendingError>(),
+    )?;
+    Ok(())
+}
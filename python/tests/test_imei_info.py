@@ -0,0 +1,57 @@
+"""Tests for the `imei_info` Python bindings. API calls are not tested, since they need an API key."""
+
+import pytest
+
+import imei_info
+
+IPHONE_X_IMEI = "356741089728686"
+SAMSUNG_S10_IMEI = "351725105350612"
+
+
+def test_imei_accessors():
+    imei = imei_info.Imei(IPHONE_X_IMEI)
+    assert str(imei) == IPHONE_X_IMEI
+    assert repr(imei) == f"Imei('{IPHONE_X_IMEI}')"
+    assert int(imei) == int(IPHONE_X_IMEI)
+    assert imei.reporting_body == "35"
+    assert imei.type_allocation_code == "35674108"
+    assert imei.serial_number == "972868"
+    assert imei.check_digit == 6
+    assert imei.tac() == imei_info.Tac("35674108")
+    assert imei == imei_info.Imei(int(IPHONE_X_IMEI))
+    assert len({imei, imei_info.Imei(IPHONE_X_IMEI)}) == 1
+
+
+def test_invalid_imeis():
+    with pytest.raises(ValueError):
+        imei_info.Imei("356741089728687")
+    with pytest.raises(ValueError):
+        imei_info.Imei("35674108972868")
+    with pytest.raises(TypeError):
+        imei_info.Imei(None)
+
+
+def test_lenient_parsing():
+    assert imei_info.Imei.parse_lenient("35-674108-972868-6") == imei_info.Imei(IPHONE_X_IMEI)
+    assert imei_info.Tac.parse_lenient("35 674108") == imei_info.Tac("35674108")
+    with pytest.raises(ValueError):
+        imei_info.Imei.parse_lenient("35_674108_972868_6")
+
+
+def test_tac_to_imei():
+    imei = imei_info.Tac("35172510").to_imei()
+    assert imei.type_allocation_code == "35172510"
+    assert imei_info.is_valid_imei(imei)
+
+
+def test_validate_imeis():
+    values = [IPHONE_X_IMEI, "356741089728687", "35 674108 972868 6", None, 356741089728686]
+    assert imei_info.validate_imeis(values) == [True, False, False, False, True]
+    assert imei_info.validate_imeis(values, lenient=True) == [True, False, True, False, True]
+    assert imei_info.validate_imeis(iter([SAMSUNG_S10_IMEI])) == [True]
+
+
+def test_get_imei_info_invalid_imei():
+    # * Invalid IMEIs are rejected before any request is made
+    with pytest.raises(ValueError):
+        imei_info.get_imei_info("valid", "356741089728687")
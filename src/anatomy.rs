@@ -0,0 +1,225 @@
+use core::fmt::Display;
+use core::ops::Range;
+
+use crate::wrapper::{luhn_checksum, Imei, ImeiSv};
+
+/// Reporting bodies which have allocated TACs, along with the ones which only did so under the pre-2004 scheme.
+pub(crate) const REPORTING_BODIES: &[([u8; 2], &str)] = &[
+    ([0, 0], "Test IMEI"),
+    ([0, 1], "PTCRB (United States)"),
+    ([1, 0], "DECT PP with GSM functionality"),
+    ([3, 0], "Iridium"),
+    ([3, 3], "DGPT (France)"),
+    ([3, 5], "BABT (United Kingdom)"),
+    ([4, 4], "BABT (United Kingdom)"),
+    ([4, 5], "NTA (Denmark)"),
+    ([4, 9], "BZT / BAPT (Germany)"),
+    ([5, 0], "BZT ETS (Germany)"),
+    ([5, 1], "Cetecom ICT (Germany)"),
+    ([5, 2], "Cetecom (Germany)"),
+    ([5, 3], "TÜV (Germany)"),
+    ([5, 4], "Phoenix Test Lab (Germany)"),
+    ([8, 6], "TAF (China)"),
+    ([9, 1], "MSAI (India)"),
+    ([9, 8], "BABT (United Kingdom)"),
+    ([9, 9], "GHA (Global Hexadecimal Administrator)"),
+];
+
+/// Reporting bodies which only allocated codes before 2004, so their IMEIs always use the legacy TAC/FAC structure.
+const LEGACY_REPORTING_BODIES: &[[u8; 2]] = &[
+    [3, 3],
+    [4, 4],
+    [4, 5],
+    [4, 9],
+    [5, 0],
+    [5, 1],
+    [5, 2],
+    [5, 3],
+    [5, 4],
+];
+
+/// A breakdown of the parts of an IMEI or IMEISV, created by [`Imei::explain`] or [`ImeiSv::explain`].
+/// Its `Display` implementation renders one labeled line per part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImeiAnatomy {
+    pub reporting_body: [u8; 2],
+    /// The name of the reporting body which allocated the TAC, if it is known.
+    pub reporting_body_name: Option<&'static str>,
+    pub model_identifier: [u8; 6],
+    pub type_allocation_code: [u8; 8],
+    /// The pre-2004 interpretation of the first eight digits, if the reporting body only allocated codes under that scheme.
+    pub legacy: Option<LegacyAllocation>,
+    pub serial_number: [u8; 6],
+    /// The check digit of an IMEI, or `None` for an IMEISV.
+    pub check_digit: Option<u8>,
+    /// The check digit computed from the first 14 digits using Luhn's algorithm.
+    pub expected_check_digit: u8,
+    /// The software version number of an IMEISV, or `None` for an IMEI.
+    pub software_version_number: Option<[u8; 2]>,
+}
+
+/// The pre-2004 structure of the first eight digits of an IMEI, where the six-digit type approval code
+/// was followed by a two-digit final assembly code (FAC) identifying the factory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LegacyAllocation {
+    pub type_approval_code: [u8; 6],
+    pub final_assembly_code: [u8; 2],
+}
+
+/// A labeled part of an IMEI or IMEISV, as listed by [`ImeiAnatomy::segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImeiSegment<'a> {
+    pub label: &'static str,
+    /// The zero-based digit positions of the segment within the number.
+    pub position: Range<usize>,
+    pub digits: &'a [u8],
+    /// A short explanation of the segment, such as the name of the reporting body or whether the check digit is valid.
+    pub note: Option<&'static str>,
+}
+
+impl Imei {
+    /// Break the IMEI down into its parts, for displaying an annotated version of the number.
+    pub fn explain(&self) -> ImeiAnatomy {
+        ImeiAnatomy::new(self.without_check_digit(), Some(self.check_digit()), None)
+    }
+}
+
+impl ImeiSv {
+    /// Break the IMEISV down into its parts, for displaying an annotated version of the number.
+    pub fn explain(&self) -> ImeiAnatomy {
+        ImeiAnatomy::new(
+            self.digits[..14].try_into().unwrap(),
+            None,
+            Some(*self.software_version_number()),
+        )
+    }
+}
+
+impl ImeiAnatomy {
+    fn new(
+        digits: &[u8; 14],
+        check_digit: Option<u8>,
+        software_version_number: Option<[u8; 2]>,
+    ) -> Self {
+        let reporting_body: [u8; 2] = digits[0..2].try_into().unwrap();
+        let legacy = LEGACY_REPORTING_BODIES
+            .contains(&reporting_body)
+            .then(|| LegacyAllocation {
+                type_approval_code: digits[0..6].try_into().unwrap(),
+                final_assembly_code: digits[6..8].try_into().unwrap(),
+            });
+
+        Self {
+            reporting_body,
+            reporting_body_name: REPORTING_BODIES
+                .iter()
+                .find(|(code, _)| *code == reporting_body)
+                .map(|(_, name)| *name),
+            model_identifier: digits[2..8].try_into().unwrap(),
+            type_allocation_code: digits[0..8].try_into().unwrap(),
+            legacy,
+            serial_number: digits[8..14].try_into().unwrap(),
+            check_digit,
+            expected_check_digit: luhn_checksum(digits),
+            software_version_number,
+        }
+    }
+
+    /// Check whether the check digit matches the Luhn checksum. This is always `true` for an IMEISV,
+    /// which has no check digit.
+    pub fn is_check_digit_valid(&self) -> bool {
+        self.check_digit
+            .is_none_or(|check_digit| check_digit == self.expected_check_digit)
+    }
+
+    /// List the non-overlapping parts of the number in order, with human-readable labels.
+    /// For legacy IMEIs, the type approval code and final assembly code take the place of the model identifier.
+    pub fn segments(&self) -> impl Iterator<Item = ImeiSegment<'_>> {
+        let reporting_body = ImeiSegment {
+            label: "Reporting body",
+            position: 0..2,
+            digits: &self.reporting_body,
+            note: self.reporting_body_name,
+        };
+
+        let (model, final_assembly) = match &self.legacy {
+            Some(legacy) => (
+                ImeiSegment {
+                    label: "Type approval code",
+                    position: 2..6,
+                    digits: &legacy.type_approval_code[2..],
+                    note: Some("Forms the pre-2004 type approval code with the reporting body"),
+                },
+                Some(ImeiSegment {
+                    label: "Final assembly code",
+                    position: 6..8,
+                    digits: &legacy.final_assembly_code,
+                    note: Some("Identifies the factory"),
+                }),
+            ),
+            None => (
+                ImeiSegment {
+                    label: "Model identifier",
+                    position: 2..8,
+                    digits: &self.model_identifier,
+                    note: Some("Forms the type allocation code with the reporting body"),
+                },
+                None,
+            ),
+        };
+
+        let serial_number = ImeiSegment {
+            label: "Serial number",
+            position: 8..14,
+            digits: &self.serial_number,
+            note: None,
+        };
+
+        let last = match (&self.check_digit, &self.software_version_number) {
+            (_, Some(software_version_number)) => ImeiSegment {
+                label: "Software version number",
+                position: 14..16,
+                digits: software_version_number,
+                note: None,
+            },
+            (check_digit, None) => ImeiSegment {
+                label: "Check digit",
+                position: 14..15,
+                digits: core::slice::from_ref(check_digit.as_ref().unwrap_or(&0)),
+                note: Some(if self.is_check_digit_valid() {
+                    "Valid"
+                } else {
+                    "Does not match the Luhn checksum"
+                }),
+            },
+        };
+
+        [
+            Some(reporting_body),
+            Some(model),
+            final_assembly,
+            Some(serial_number),
+            Some(last),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl Display for ImeiAnatomy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+
+            write!(f, "{}: ", segment.label)?;
+            segment.digits.iter().try_for_each(|d| write!(f, "{d}"))?;
+            if let Some(note) = segment.note {
+                write!(f, " ({note})")?;
+            }
+        }
+
+        Ok(())
+    }
+}
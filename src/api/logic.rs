@@ -1,20 +1,28 @@
+use std::fmt::Display;
+
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 
 use crate::api::ServiceCheckStandardResponseBody;
 use crate::error::{Result, ServiceCheckError};
-use crate::wrapper::Imei;
+use crate::Service;
+
+/// The base URL of the IMEI.info API.
+const API_URL: &str = "https://dash.imei.info/api";
 
-pub(crate) async fn check_imei_with_service(
-    service_id: u32,
+/// Check an identifier with a service. Every service takes its identifier in the `imei` query parameter,
+/// whether it is an IMEI or another kind of identifier such as an [`EsimIdentifier`](crate::EsimIdentifier).
+pub(crate) async fn check_with_service<T: DeserializeOwned>(
+    service: Service,
     api_key: &str,
-    imei: &Imei,
-) -> Result<ServiceCheckStandardResponseBody> {
+    identifier: &impl Display,
+) -> Result<ServiceCheckStandardResponseBody<T>> {
     let client = Client::new();
     let response = client
-        .get(format!("https://dash.imei.info/api/check/{service_id}"))
-        .query(&[("API_KEY", api_key), ("imei", &imei.to_string())])
+        .get(format!("{API_URL}/check/{}", service.id()))
+        .query(&[("API_KEY", api_key), ("imei", &identifier.to_string())])
         .send()
         .await?;
 
-    Ok(ServiceCheckError::classify_response(response).await?)
+    ServiceCheckError::classify_response(response).await
 }
@@ -1,41 +1,9 @@
-#![allow(unused)]
-
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
 use serde::Deserialize;
+use ulid::Ulid;
 
-const SAMSUNG_INFO_CHECK_SID: u32 = 4;
-const SAMSUNG_KNOX_INFO_CHECK_SID: u32 = 76;
-const GOOGLE_INFO_CHECK_SID: u32 = 54;
-const LG_INFO_CHECK_SID: u32 = 66;
-const SONY_INFO_CHECK_SID: u32 = 80;
-const XIAOMI_INFO_CHECK_SID: u32 = 84;
-const XIAOMI_MI_LOCK_INFO_CHECK_SID: u32 = 86;
-
-const APPLE_FMI_STATUS_SID: u32 = 0;
-const APPLE_WARRANTY_CHECK_SID: u32 = 12;
-const APPLE_SIMLOCK_CHECK_SID: u32 = 104;
-const APPLE_CARRIER_LOCK_FMI_STATUS_SID: u32 = 2;
-const APPLE_SOLD_BY_WARRANTY_COVERAGE_SID: u32 = 11;
-
-pub(crate) const BASIC_IMEI_CHECK_SID: u32 = 0;
-const BLACKLIST_SIMPLE_CHECK_SID: u32 = 27;
-const BLACKLIST_PREMIUM_CHECK_SID: u32 = 3;
-const CARRIER_LOOKUP_SID: u32 = 48;
-const ESIM_INFO_CHECK_SID: u32 = 52;
-const MAC_ADDRESS_CHECK_SID: u32 = 106;
-const VERIZON_USA_CHECK_SID: u32 = 32;
-const TMOBILE_USA_CHECK_SID: u32 = 31;
-const LOST_DEVICE_ADD_SID: u32 = 100;
-const LOST_DEVICE_CHECK_SID: u32 = 101;
-
-#[derive(Deserialize, Debug)]
-pub(crate) enum ServiceCheckStatus {
-    Done,
-    #[serde(rename = "In_progress")]
-    InProgress,
-    Completed,
-    Rejected,
-}
+use crate::{CheckStatus, Service, ServiceCheck};
 
 #[derive(Deserialize, Debug)]
 pub(crate) struct ApiPhoneInfo {
@@ -44,11 +12,378 @@ pub(crate) struct ApiPhoneInfo {
     pub(crate) model: String,
 }
 
+impl ServiceCheck for ApiPhoneInfo {
+    const SERVICE: Service = Service::BasicImeiCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiMacAddressInfo {
+    pub(crate) vendor: String,
+    #[serde(default)]
+    pub(crate) address: Option<String>,
+    #[serde(default)]
+    pub(crate) country: Option<String>,
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) device_type: Option<String>,
+}
+
+impl ServiceCheck for ApiMacAddressInfo {
+    const SERVICE: Service = Service::MacAddressCheck;
+}
+
+/// A yes/no value in a result, which services report either as a JSON boolean or as a string such as
+/// `"ON"`, `"Yes"`, or `"Locked"`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub(crate) enum ApiFlag {
+    Bool(bool),
+    Text(String),
+}
+
+impl ApiFlag {
+    /// Interpret the value, returning `None` if it is not recognized (such as `"Unknown"`).
+    pub(crate) fn value(&self) -> Option<bool> {
+        match self {
+            ApiFlag::Bool(value) => Some(*value),
+            ApiFlag::Text(text) => match text.trim().to_ascii_lowercase().as_str() {
+                "on" | "yes" | "true" | "enabled" | "active" | "locked" | "1" => Some(true),
+                "off" | "no" | "false" | "disabled" | "inactive" | "unlocked" | "0" => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    /// Interpret the value as with [`ApiFlag::value`], but also accept words which only mean yes or no for
+    /// a particular field, such as `"Clean"` for a blacklist status.
+    pub(crate) fn value_with(&self, yes: &[&str], no: &[&str]) -> Option<bool> {
+        if let ApiFlag::Text(text) = self {
+            let text = text.trim();
+            if yes.iter().any(|word| text.eq_ignore_ascii_case(word)) {
+                return Some(true);
+            } else if no.iter().any(|word| text.eq_ignore_ascii_case(word)) {
+                return Some(false);
+            }
+        }
+        self.value()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiAppleCarrierLockFmiStatus {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) fmi_on: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) lost_mode: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) sim_locked: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) carrier: Option<String>,
+    #[serde(default)]
+    pub(crate) carrier_policy: Option<String>,
+}
+
+impl ServiceCheck for ApiAppleCarrierLockFmiStatus {
+    const SERVICE: Service = Service::AppleCarrierLockFmiStatus;
+}
+
+/// Parse a date in a result, which services report in several formats, returning `None` if it is not recognized.
+pub(crate) fn parse_api_date(date: Option<&str>) -> Option<NaiveDate> {
+    let date = date?.trim();
+    // * Timestamps are cut down to their date, since the time is always midnight
+    let date = date
+        .get(..10)
+        .filter(|_| date.contains('T'))
+        .unwrap_or(date);
+    ["%Y-%m-%d", "%Y/%m/%d", "%B %d, %Y", "%b %d, %Y", "%d %B %Y"]
+        .into_iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiAppleWarranty {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) purchase_date: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_start_date: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_end_date: Option<String>,
+    #[serde(default)]
+    pub(crate) applecare: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) replaced: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) refurbished: Option<ApiFlag>,
+}
+
+impl ServiceCheck for ApiAppleWarranty {
+    const SERVICE: Service = Service::AppleWarrantyCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiAppleSoldByWarranty {
+    #[serde(flatten)]
+    pub(crate) warranty: ApiAppleWarranty,
+    #[serde(default)]
+    pub(crate) sold_by: Option<String>,
+    #[serde(default)]
+    pub(crate) purchase_country: Option<String>,
+}
+
+impl ServiceCheck for ApiAppleSoldByWarranty {
+    const SERVICE: Service = Service::AppleSoldByWarrantyCoverage;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiAppleSimlock {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) simlock: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) next_tether_policy: Option<String>,
+    #[serde(default)]
+    pub(crate) initial_carrier_policy: Option<String>,
+    #[serde(default)]
+    pub(crate) policy_country: Option<String>,
+}
+
+impl ServiceCheck for ApiAppleSimlock {
+    const SERVICE: Service = Service::AppleSimlockCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiSamsungInfo {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) model_name: Option<String>,
+    #[serde(default)]
+    pub(crate) product_code: Option<String>,
+    #[serde(default)]
+    pub(crate) csc: Option<String>,
+    #[serde(default)]
+    pub(crate) sold_country: Option<String>,
+    #[serde(default)]
+    pub(crate) manufacture_date: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_end: Option<String>,
+}
+
+impl ServiceCheck for ApiSamsungInfo {
+    const SERVICE: Service = Service::SamsungInfoCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiSamsungKnoxInfo {
+    #[serde(default)]
+    pub(crate) knox_guard: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) knox_enrollment: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) enrolled_organization: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_void: Option<ApiFlag>,
+}
+
+impl ServiceCheck for ApiSamsungKnoxInfo {
+    const SERVICE: Service = Service::SamsungKnoxInfoCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiXiaomiInfo {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) model_code: Option<String>,
+    #[serde(default)]
+    pub(crate) region: Option<String>,
+    #[serde(default)]
+    pub(crate) sold_country: Option<String>,
+    #[serde(default)]
+    pub(crate) activation_status: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) activation_date: Option<String>,
+    #[serde(default)]
+    pub(crate) manufacture_date: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_end: Option<String>,
+}
+
+impl ServiceCheck for ApiXiaomiInfo {
+    const SERVICE: Service = Service::XiaomiInfoCheck;
+}
+
 #[derive(Deserialize, Debug)]
-pub(crate) struct ServiceCheckStandardResponseBody {
+pub(crate) struct ApiXiaomiMiLockInfo {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) mi_lock_status: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) lost_mode: Option<ApiFlag>,
+}
+
+impl ServiceCheck for ApiXiaomiMiLockInfo {
+    const SERVICE: Service = Service::XiaomiMiLockInfoCheck;
+}
+
+// * The fields which the Google, LG, and Sony info checks share
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiManufacturerInfo {
+    #[serde(default)]
+    pub(crate) model: Option<String>,
+    #[serde(default)]
+    pub(crate) sku: Option<String>,
+    #[serde(default)]
+    pub(crate) variant: Option<String>,
+    #[serde(default)]
+    pub(crate) region: Option<String>,
+    #[serde(default)]
+    pub(crate) sold_country: Option<String>,
+    #[serde(default)]
+    pub(crate) manufacture_date: Option<String>,
+    #[serde(default)]
+    pub(crate) warranty_end: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiGoogleInfo {
+    #[serde(flatten)]
+    pub(crate) info: ApiManufacturerInfo,
+    #[serde(default)]
+    pub(crate) color: Option<String>,
+    #[serde(default)]
+    pub(crate) storage: Option<String>,
+}
+
+impl ServiceCheck for ApiGoogleInfo {
+    const SERVICE: Service = Service::GoogleInfoCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiLgInfo {
+    #[serde(flatten)]
+    pub(crate) info: ApiManufacturerInfo,
+    #[serde(default)]
+    pub(crate) buyer_code: Option<String>,
+    #[serde(default)]
+    pub(crate) carrier: Option<String>,
+}
+
+impl ServiceCheck for ApiLgInfo {
+    const SERVICE: Service = Service::LgInfoCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiSonyInfo {
+    #[serde(flatten)]
+    pub(crate) info: ApiManufacturerInfo,
+    #[serde(default)]
+    pub(crate) color: Option<String>,
+    #[serde(default)]
+    pub(crate) customization: Option<String>,
+}
+
+impl ServiceCheck for ApiSonyInfo {
+    const SERVICE: Service = Service::SonyInfoCheck;
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiBlacklistRecord {
+    #[serde(default)]
+    pub(crate) carrier: Option<String>,
+    #[serde(default)]
+    pub(crate) country: Option<String>,
+    #[serde(default)]
+    pub(crate) reason: Option<String>,
+    #[serde(default)]
+    pub(crate) reported_at: Option<String>,
+}
+
+// * The simple check reports a single record at the top level, and the premium check reports a list of them
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiBlacklistStatus {
+    #[serde(default)]
+    pub(crate) blacklist: Option<ApiFlag>,
+    #[serde(flatten)]
+    pub(crate) record: ApiBlacklistRecord,
+    #[serde(default)]
+    pub(crate) records: Vec<ApiBlacklistRecord>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub(crate) struct ApiBlacklistSimpleStatus(pub(crate) ApiBlacklistStatus);
+
+impl ServiceCheck for ApiBlacklistSimpleStatus {
+    const SERVICE: Service = Service::BlacklistSimpleCheck;
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub(crate) struct ApiBlacklistPremiumStatus(pub(crate) ApiBlacklistStatus);
+
+impl ServiceCheck for ApiBlacklistPremiumStatus {
+    const SERVICE: Service = Service::BlacklistPremiumCheck;
+}
+
+// * The carrier lookup and the US carrier checks report the same fields, each for their own networks
+#[derive(Deserialize, Debug)]
+pub(crate) struct ApiCarrierStatus {
+    #[serde(default)]
+    pub(crate) original_carrier: Option<String>,
+    #[serde(default)]
+    pub(crate) locked_carrier: Option<String>,
+    #[serde(default)]
+    pub(crate) country_code: Option<String>,
+    #[serde(default)]
+    pub(crate) sim_locked: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) financed: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) unpaid_balance: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) blacklist: Option<ApiFlag>,
+    #[serde(default)]
+    pub(crate) activation_eligible: Option<ApiFlag>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub(crate) struct ApiCarrierLookupStatus(pub(crate) ApiCarrierStatus);
+
+impl ServiceCheck for ApiCarrierLookupStatus {
+    const SERVICE: Service = Service::CarrierLookup;
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub(crate) struct ApiVerizonStatus(pub(crate) ApiCarrierStatus);
+
+impl ServiceCheck for ApiVerizonStatus {
+    const SERVICE: Service = Service::VerizonUsaCheck;
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(transparent)]
+pub(crate) struct ApiTMobileStatus(pub(crate) ApiCarrierStatus);
+
+impl ServiceCheck for ApiTMobileStatus {
+    const SERVICE: Service = Service::TMobileUsaCheck;
+}
+
+// * This does not implement `Debug` so that the token key cannot be logged by accident; use `CheckRecord` instead
+#[derive(Deserialize)]
+pub(crate) struct ServiceCheckStandardResponseBody<T> {
     pub(crate) id: u32,
-    pub(crate) ulid: Option<String>,
-    pub(crate) status: ServiceCheckStatus,
+    pub(crate) ulid: Option<Ulid>,
+    pub(crate) status: CheckStatus,
     pub(crate) service: String,
     pub(crate) service_id: u32,
     pub(crate) created_at: DateTime<Utc>,
@@ -58,14 +393,13 @@ pub(crate) struct ServiceCheckStandardResponseBody {
     pub(crate) phone_number: Option<String>,
     pub(crate) text: Option<String>,
     pub(crate) token_key: String,
-    pub(crate) token_request_price: String,
-    pub(crate) result: ApiPhoneInfo,
+    pub(crate) token_request_price: Decimal,
+    pub(crate) result: T,
     pub(crate) requested_at: DateTime<Utc>,
 }
 
 #[derive(Deserialize, Debug)]
 pub(crate) struct ServiceCheckPendingResponseBody {
-    pub(crate) message: String,
     pub(crate) history_id: String,
     pub(crate) ulid: String,
 }
@@ -0,0 +1,219 @@
+use core::ops::AddAssign;
+
+use crate::wrapper::{Imei, ImeiWrapperError};
+
+/// Lookup table for the Luhn algorithm, mapping each digit to the sum of the digits of its double.
+const LUHN_DOUBLED: [u8; 10] = [0, 2, 4, 6, 8, 1, 3, 5, 7, 9];
+
+/// The ASCII character `'0'` repeated in every byte of a word.
+const ASCII_ZEROES: u64 = 0x3030_3030_3030_3030;
+
+/// The layout of the records in a buffer passed to [`validate_bulk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// One IMEI per line.
+    Lines,
+    /// One record per line, with the IMEI in the given zero-based column. Fields may be wrapped in
+    /// double quotes, but quoted fields containing the separator are not supported.
+    Csv {
+        column: usize,
+        separator: u8,
+        has_header: bool,
+    },
+}
+
+/// The result of validating a single record of a buffer passed to [`validate_bulk`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkRecord<'a> {
+    /// The one-based line number of the record.
+    pub line: usize,
+    /// The raw bytes of the IMEI field, with surrounding whitespace and quotes removed.
+    pub field: &'a [u8],
+    pub result: Result<Imei, ImeiWrapperError>,
+}
+
+/// Aggregate counts for the records of a buffer passed to [`validate_bulk`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkSummary {
+    pub records: usize,
+    pub valid: usize,
+    pub incorrect_length: usize,
+    pub cannot_parse_digits: usize,
+    pub checksum_does_not_match: usize,
+}
+
+/// An iterator over the validated records of a buffer, created by [`validate_bulk`].
+#[derive(Debug, Clone)]
+pub struct BulkRecords<'a> {
+    remaining: &'a [u8],
+    format: RecordFormat,
+    line: usize,
+}
+
+/// Validate every IMEI in a buffer of newline-delimited records, such as the contents of a text or CSV file.
+/// Blank lines (and, for CSV, the header) are skipped, and both `\n` and `\r\n` line endings are accepted.
+///
+/// This does not allocate, and it validates each record directly from its bytes, which is considerably
+/// faster than calling [`Imei::from_str`](core::str::FromStr::from_str) on each line. Call
+/// [`BulkRecords::summary`] to only count the results.
+pub fn validate_bulk(buffer: &[u8], format: RecordFormat) -> BulkRecords<'_> {
+    let mut records = BulkRecords {
+        remaining: buffer,
+        format,
+        line: 0,
+    };
+
+    if let RecordFormat::Csv {
+        has_header: true, ..
+    } = format
+    {
+        records.next_line();
+    }
+
+    records
+}
+
+impl<'a> BulkRecords<'a> {
+    /// Consume the iterator, counting the results of every remaining record.
+    pub fn summary(self) -> BulkSummary {
+        let mut summary = BulkSummary::default();
+        for record in self {
+            summary += &record.result;
+        }
+
+        summary
+    }
+
+    fn next_line(&mut self) -> Option<&'a [u8]> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (line, remaining) = match find_newline(self.remaining) {
+            Some(end) => (&self.remaining[..end], &self.remaining[end + 1..]),
+            None => (self.remaining, &self.remaining[self.remaining.len()..]),
+        };
+
+        self.remaining = remaining;
+        self.line += 1;
+        Some(line)
+    }
+
+    fn field(&self, line: &'a [u8]) -> Option<&'a [u8]> {
+        let field = match self.format {
+            RecordFormat::Lines => line,
+            RecordFormat::Csv {
+                column, separator, ..
+            } => line.split(|b| *b == separator).nth(column)?,
+        };
+
+        let field = field.trim_ascii();
+        Some(
+            field
+                .strip_prefix(b"\"")
+                .and_then(|field| field.strip_suffix(b"\""))
+                .unwrap_or(field),
+        )
+    }
+}
+
+impl<'a> Iterator for BulkRecords<'a> {
+    type Item = BulkRecord<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.next_line()?;
+            if line.trim_ascii().is_empty() {
+                continue;
+            }
+
+            // * A line which is missing the IMEI column entirely is reported as an empty field
+            let field = self.field(line).unwrap_or_default();
+            return Some(BulkRecord {
+                line: self.line,
+                field,
+                result: Imei::from_ascii_digits(field),
+            });
+        }
+    }
+}
+
+impl AddAssign<&Result<Imei, ImeiWrapperError>> for BulkSummary {
+    fn add_assign(&mut self, result: &Result<Imei, ImeiWrapperError>) {
+        self.records += 1;
+        match result {
+            Ok(_) => self.valid += 1,
+            Err(ImeiWrapperError::IncorrectLength) => self.incorrect_length += 1,
+            Err(ImeiWrapperError::CannotParseDigits) | Err(ImeiWrapperError::ValueOutOfRange) => {
+                self.cannot_parse_digits += 1
+            }
+            Err(ImeiWrapperError::ChecksumDoesNotMatch) => self.checksum_does_not_match += 1,
+        }
+    }
+}
+
+impl Imei {
+    /// Parse an IMEI from exactly 15 ASCII digits. This is the fast path used by [`validate_bulk`]:
+    /// it checks all of the characters at once and computes the checksum using a lookup table.
+    pub fn from_ascii_digits(bytes: &[u8]) -> Result<Self, ImeiWrapperError> {
+        let Ok(bytes) = <&[u8; 15]>::try_from(bytes) else {
+            return Err(ImeiWrapperError::IncorrectLength);
+        };
+
+        // * The two words overlap by one byte, which is simpler and faster than handling a 7-byte tail
+        let low = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let high = u64::from_le_bytes(bytes[7..15].try_into().unwrap());
+        if !all_ascii_digits(low) || !all_ascii_digits(high) {
+            return Err(ImeiWrapperError::CannotParseDigits);
+        }
+
+        // * Once every byte is known to be a digit, subtracting `'0'` from each of them cannot borrow
+        let mut digits = [0u8; 15];
+        digits[0..8].copy_from_slice(&(low - ASCII_ZEROES).to_le_bytes());
+        digits[7..15].copy_from_slice(&(high - ASCII_ZEROES).to_le_bytes());
+
+        let mut checksum = 0;
+        for pair in digits[..14].chunks_exact(2) {
+            checksum += pair[0] + LUHN_DOUBLED[pair[1] as usize];
+        }
+
+        if (10 - checksum % 10) % 10 != digits[14] {
+            return Err(ImeiWrapperError::ChecksumDoesNotMatch);
+        }
+
+        Ok(Self { digits })
+    }
+}
+
+/// Find the first newline in a buffer, checking eight bytes at a time.
+fn find_newline(buffer: &[u8]) -> Option<usize> {
+    const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+    const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+    const NEWLINES: u64 = LOW_BITS * b'\n' as u64;
+
+    let mut chunks = buffer.chunks_exact(8);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        // * Any byte which was a newline becomes zero, which sets its high bit in this expression
+        let word = u64::from_le_bytes(chunk.try_into().unwrap()) ^ NEWLINES;
+        let zero_bytes = word.wrapping_sub(LOW_BITS) & !word & HIGH_BITS;
+        if zero_bytes != 0 {
+            return Some(i * 8 + zero_bytes.trailing_zeros() as usize / 8);
+        }
+    }
+
+    let tail = chunks.remainder();
+    tail.iter()
+        .position(|b| *b == b'\n')
+        .map(|position| buffer.len() - tail.len() + position)
+}
+
+/// Check whether all eight bytes of a word are ASCII digits, using SWAR (SIMD within a register).
+/// A byte is a digit if it has no high bit, adding `0x46` does not set its high bit, and
+/// subtracting `0x30` does not set its high bit. Carries and borrows between bytes can only
+/// originate from bytes which are already non-digits, so they cannot hide an invalid byte.
+const fn all_ascii_digits(word: u64) -> bool {
+    const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+    let above_nine = word.wrapping_add(0x4646_4646_4646_4646);
+    let below_zero = word.wrapping_sub(ASCII_ZEROES);
+    (word | above_nine | below_zero) & HIGH_BITS == 0
+}
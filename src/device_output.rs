@@ -0,0 +1,260 @@
+use core::error::Error;
+use core::fmt::Display;
+use core::str::{FromStr, Lines};
+
+use crate::wrapper::{Imei, ImeiSv, ImeiWrapperError};
+
+/// An identifier read from a device, either over a modem's AT command interface or through adb.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportedIdentity {
+    Imei(Imei),
+    ImeiSv(ImeiSv),
+    /// A bare two-digit software version number (SVN), as reported by `AT+CGSN=3` or the `IMEI SV` line of `ATI`.
+    SoftwareVersion([u8; 2]),
+}
+
+/// An identifier read from a device, along with the zero-based SIM slot it belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotIdentity {
+    pub slot: usize,
+    pub identity: ReportedIdentity,
+}
+
+/// An iterator over the identifiers listed in the output of `ATI` or `dumpsys iphonesubinfo`, created by
+/// [`parse_ati_response`] or [`parse_dumpsys_iphonesubinfo`].
+#[derive(Debug, Clone)]
+pub struct ReportedIdentities<'a> {
+    lines: Lines<'a>,
+    format: ListingFormat,
+    next_slot: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ListingFormat {
+    Ati,
+    Dumpsys,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceOutputError {
+    MissingIdentifier,
+    CommandFailed,
+    InvalidIdentifier(ImeiWrapperError),
+    MalformedParcel,
+    ParcelException { code: i32 },
+}
+
+impl Error for DeviceOutputError {}
+
+impl Display for DeviceOutputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeviceOutputError::MissingIdentifier => {
+                f.write_str("the output does not contain an identifier")
+            }
+            DeviceOutputError::CommandFailed => {
+                f.write_str("the device returned an error for the command")
+            }
+            DeviceOutputError::InvalidIdentifier(error) => {
+                write!(f, "the identifier in the output is invalid: {error}")
+            }
+            DeviceOutputError::MalformedParcel => {
+                f.write_str("the parcel dump could not be decoded")
+            }
+            DeviceOutputError::ParcelException { code } => {
+                write!(f, "the service call returned exception code {code}")
+            }
+        }
+    }
+}
+
+impl From<ImeiWrapperError> for DeviceOutputError {
+    fn from(error: ImeiWrapperError) -> Self {
+        DeviceOutputError::InvalidIdentifier(error)
+    }
+}
+
+/// Parse the response to a modem command which returns a single identifier. This covers `AT+CGSN`,
+/// the `AT+CGSN=<snt>` variants from 3GPP TS 27.007 (IMEI, IMEISV, and SVN), `AT+GSN`, and MediaTek's
+/// `AT+EGMR=0,7` and `AT+EGMR=0,10` for the first and second SIM slots.
+///
+/// The command echo, blank lines, and the final `OK` are ignored, and an `ERROR` or `+CME ERROR` result
+/// is reported as [`DeviceOutputError::CommandFailed`].
+pub fn parse_at_response(response: &str) -> Result<ReportedIdentity, DeviceOutputError> {
+    for line in response.lines().map(str::trim) {
+        if line == "ERROR" || line.starts_with("+CME ERROR") {
+            return Err(DeviceOutputError::CommandFailed);
+        }
+
+        let value = ["+CGSN:", "+GSN:", "+EGMR:"]
+            .into_iter()
+            .find_map(|prefix| line.strip_prefix(prefix))
+            .unwrap_or(line)
+            .trim()
+            .trim_matches('"');
+
+        if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            return identity_from_digits(value);
+        }
+    }
+
+    Err(DeviceOutputError::MissingIdentifier)
+}
+
+/// Parse the identifiers listed in the response to `ATI`, such as `IMEI: ...`, `IMEI SV: ...`, or
+/// `IMEI1: ...` and `IMEI2: ...` on dual-SIM modems. Lines which do not contain an identifier are skipped.
+pub fn parse_ati_response(response: &str) -> ReportedIdentities<'_> {
+    ReportedIdentities {
+        lines: response.lines(),
+        format: ListingFormat::Ati,
+        next_slot: 0,
+    }
+}
+
+/// Parse the `Device ID = ...` lines in the output of `adb shell dumpsys iphonesubinfo`, which lists one
+/// subscriber per SIM slot in order.
+pub fn parse_dumpsys_iphonesubinfo(output: &str) -> ReportedIdentities<'_> {
+    ReportedIdentities {
+        lines: output.lines(),
+        format: ListingFormat::Dumpsys,
+        next_slot: 0,
+    }
+}
+
+/// Parse the parcel dump returned by `adb shell service call iphonesubinfo <code> [i32 <slot>]`, which
+/// contains a status word followed by a UTF-16 string.
+///
+/// A non-zero status word means the call threw an exception (usually a `SecurityException` because the
+/// shell user is not allowed to read device identifiers), which is reported as
+/// [`DeviceOutputError::ParcelException`]. A null string is reported as [`DeviceOutputError::MissingIdentifier`].
+pub fn parse_service_call_parcel(output: &str) -> Result<ReportedIdentity, DeviceOutputError> {
+    let mut words = output.lines().flat_map(parcel_line_words);
+    let status = words.next().ok_or(DeviceOutputError::MalformedParcel)?;
+    if status != 0 {
+        return Err(DeviceOutputError::ParcelException {
+            code: status as i32,
+        });
+    }
+
+    let length = match words.next() {
+        Some(u32::MAX) => return Err(DeviceOutputError::MissingIdentifier),
+        Some(length) => length as usize,
+        None => return Err(DeviceOutputError::MalformedParcel),
+    };
+
+    let mut characters = [0u8; 16];
+    if length > characters.len() {
+        return Err(ImeiWrapperError::IncorrectLength.into());
+    }
+
+    // * Each word holds two UTF-16 code units, with the first one in the low half
+    let mut units = words.flat_map(|word| [word as u16, (word >> 16) as u16]);
+    for character in &mut characters[..length] {
+        let unit = units.next().ok_or(DeviceOutputError::MalformedParcel)?;
+        *character = u8::try_from(unit)
+            .ok()
+            .filter(u8::is_ascii)
+            .ok_or(DeviceOutputError::MalformedParcel)?;
+    }
+
+    let value = core::str::from_utf8(&characters[..length]).unwrap();
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ImeiWrapperError::CannotParseDigits.into());
+    }
+
+    identity_from_digits(value)
+}
+
+impl Iterator for ReportedIdentities<'_> {
+    type Item = Result<SlotIdentity, DeviceOutputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            let (slot, value) = match self.format {
+                ListingFormat::Ati => {
+                    let Some((key, value)) = line.split_once(':') else {
+                        continue;
+                    };
+
+                    let Some(slot) = ati_key_slot(key) else {
+                        continue;
+                    };
+
+                    (slot, value)
+                }
+                ListingFormat::Dumpsys => {
+                    let Some((key, value)) = line.split_once('=') else {
+                        continue;
+                    };
+
+                    if key.trim() != "Device ID" {
+                        continue;
+                    }
+
+                    self.next_slot += 1;
+                    (self.next_slot - 1, value)
+                }
+            };
+
+            return Some(
+                identity_from_digits(value.trim()).map(|identity| SlotIdentity { slot, identity }),
+            );
+        }
+
+        None
+    }
+}
+
+/// Get the zero-based slot for an `ATI` key such as `IMEI`, `IMEI 2`, `IMEISV`, or `IMEI SV`, ignoring
+/// case, spaces, and underscores. Returns `None` if the key does not name an identifier.
+fn ati_key_slot(key: &str) -> Option<usize> {
+    let mut normalized = [0u8; 16];
+    let mut length = 0;
+    for b in key.trim().bytes().filter(|b| !matches!(b, b' ' | b'_')) {
+        *normalized.get_mut(length)? = b.to_ascii_uppercase();
+        length += 1;
+    }
+
+    let normalized = &normalized[..length];
+    let slot = ["IMEISV", "SVN", "IMEI"]
+        .into_iter()
+        .find_map(|prefix| normalized.strip_prefix(prefix.as_bytes()))?;
+
+    match slot {
+        b"" | b"1" => Some(0),
+        b"2" => Some(1),
+        _ => None,
+    }
+}
+
+/// Decode the hex words in a single line of a parcel dump, such as
+/// `0x00000010: 00310034 00380030 00370039 00380032 '4.1.0.8.9.7.2.8.'`.
+fn parcel_line_words(line: &str) -> impl Iterator<Item = u32> + '_ {
+    let line = line.split_once("Parcel(").map_or(line, |(_, rest)| rest);
+    let line = match line.split_once(':') {
+        Some((offset, rest)) if offset.trim().starts_with("0x") => rest,
+        _ => line,
+    };
+
+    line.split('\'')
+        .next()
+        .unwrap_or_default()
+        .split_whitespace()
+        .take_while(|token| token.len() == 8)
+        .map_while(|token| u32::from_str_radix(token, 16).ok())
+}
+
+fn identity_from_digits(value: &str) -> Result<ReportedIdentity, DeviceOutputError> {
+    match value.len() {
+        2 => {
+            let digits: [u8; 2] = value.as_bytes().try_into().unwrap();
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(ImeiWrapperError::CannotParseDigits.into());
+            }
+
+            Ok(ReportedIdentity::SoftwareVersion(digits.map(|d| d - b'0')))
+        }
+        16 => Ok(ReportedIdentity::ImeiSv(ImeiSv::from_str(value)?)),
+        _ => Ok(ReportedIdentity::Imei(Imei::from_str(value)?)),
+    }
+}
@@ -2,24 +2,52 @@ use std::error::Error;
 use std::fmt::Display;
 
 use reqwest::{Error as ReqwestError, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
 
 use crate::api::{
     ServiceCheckInvalidApiKeyResponseBody, ServiceCheckPendingResponseBody,
     ServiceCheckStandardResponseBody,
 };
+use crate::SanityFlags;
 
 pub(crate) type Result<T> = std::result::Result<T, ServiceCheckError>;
 
 // TODO: Maybe split these into enum with `Wrapper` and `Api` variants
 #[derive(Debug)]
 pub enum ServiceCheckError {
-    RequestPending { history_id: String, ulid: String },
+    RequestPending {
+        history_id: String,
+        ulid: String,
+    },
     InvalidImeiNumber,
+    InvalidMacAddress,
+    InvalidDeviceIdentifier,
+    InvalidPhoneNumber,
+    SuspiciousImei {
+        flags: SanityFlags,
+    },
+    UnsupportedManufacturer {
+        manufacturer: String,
+    },
     MissingApiKey,
-    InvalidApiKey { detail: String },
+    InvalidApiKey {
+        detail: String,
+    },
     InvalidServiceID,
-    UnknownRequestError { error: ReqwestError },
-    UnknownApiError { error: Response },
+    /// The check succeeded, but its result could not be interpreted. The JSON which could not be interpreted is
+    /// kept, so that a result which was already paid for is not lost.
+    MalformedResult {
+        detail: String,
+        body: Value,
+    },
+    UnknownRequestError {
+        error: ReqwestError,
+    },
+    UnknownApiError {
+        error: Response,
+    },
 }
 
 impl PartialEq for ServiceCheckError {
@@ -36,6 +64,24 @@ impl PartialEq for ServiceCheckError {
                 },
             ) => history_id_self.eq(history_id_other) && ulid_self.eq(ulid_other),
             (ServiceCheckError::InvalidImeiNumber, ServiceCheckError::InvalidImeiNumber) => true,
+            (ServiceCheckError::InvalidMacAddress, ServiceCheckError::InvalidMacAddress) => true,
+            (
+                ServiceCheckError::InvalidDeviceIdentifier,
+                ServiceCheckError::InvalidDeviceIdentifier,
+            ) => true,
+            (ServiceCheckError::InvalidPhoneNumber, ServiceCheckError::InvalidPhoneNumber) => true,
+            (
+                ServiceCheckError::SuspiciousImei { flags: flags_self },
+                ServiceCheckError::SuspiciousImei { flags: flags_other },
+            ) => flags_self.eq(flags_other),
+            (
+                ServiceCheckError::UnsupportedManufacturer {
+                    manufacturer: manufacturer_self,
+                },
+                ServiceCheckError::UnsupportedManufacturer {
+                    manufacturer: manufacturer_other,
+                },
+            ) => manufacturer_self.eq(manufacturer_other),
             (ServiceCheckError::MissingApiKey, ServiceCheckError::MissingApiKey) => true,
             (
                 ServiceCheckError::InvalidApiKey {
@@ -46,6 +92,16 @@ impl PartialEq for ServiceCheckError {
                 },
             ) => detail_self.eq(detail_other),
             (ServiceCheckError::InvalidServiceID, ServiceCheckError::InvalidServiceID) => true,
+            (
+                ServiceCheckError::MalformedResult {
+                    detail: detail_self,
+                    body: body_self,
+                },
+                ServiceCheckError::MalformedResult {
+                    detail: detail_other,
+                    body: body_other,
+                },
+            ) => detail_self.eq(detail_other) && body_self.eq(body_other),
             (
                 ServiceCheckError::UnknownRequestError { error: error_self },
                 ServiceCheckError::UnknownRequestError { error: error_other },
@@ -70,9 +126,23 @@ impl Display for ServiceCheckError {
             ServiceCheckError::InvalidImeiNumber => {
                 "IMEI or TAC number passed to wrapper is invalid"
             }
+            ServiceCheckError::InvalidMacAddress => "MAC address passed to wrapper is invalid",
+            ServiceCheckError::InvalidDeviceIdentifier => {
+                "IMEI or serial number passed to wrapper is invalid"
+            }
+            ServiceCheckError::InvalidPhoneNumber => "phone number passed to wrapper is invalid",
+            ServiceCheckError::SuspiciousImei { .. } => {
+                "IMEI passed to wrapper was refused because of its sanity flags"
+            }
+            ServiceCheckError::UnsupportedManufacturer { .. } => {
+                "manufacturer of device has no info check"
+            }
             ServiceCheckError::MissingApiKey => "API key was not provided",
             ServiceCheckError::InvalidApiKey { .. } => "API key is invalid",
             ServiceCheckError::InvalidServiceID => "service ID is invalid",
+            ServiceCheckError::MalformedResult { .. } => {
+                "result returned by API could not be interpreted; wrapper may be out-of-date"
+            }
             ServiceCheckError::UnknownRequestError { .. } => "unknown error occurred with request",
             ServiceCheckError::UnknownApiError { .. } => {
                 "unknown error occurred with API; wrapper may be out-of-date"
@@ -88,29 +158,31 @@ impl From<ReqwestError> for ServiceCheckError {
 }
 
 impl ServiceCheckError {
-    pub(crate) async fn classify_response(
+    pub(crate) async fn classify_response<T: DeserializeOwned>(
         response: Response,
-    ) -> Result<ServiceCheckStandardResponseBody> {
+    ) -> Result<ServiceCheckStandardResponseBody<T>> {
         match response.status() {
-            StatusCode::OK => Ok(response
-                .json::<ServiceCheckStandardResponseBody>()
-                .await
-                .unwrap()),
+            // * The body is kept as JSON until it is known to match `T`, because the check has already been paid for
+            // * and the API can change the shape of its results without notice
+            StatusCode::OK => {
+                let body = response.json::<Value>().await?;
+                ServiceCheckStandardResponseBody::<T>::deserialize(&body).map_err(|error| {
+                    ServiceCheckError::MalformedResult {
+                        detail: error.to_string(),
+                        body,
+                    }
+                })
+            }
             StatusCode::ACCEPTED => {
-                let ServiceCheckPendingResponseBody {
-                    history_id, ulid, ..
-                } = response
-                    .json::<ServiceCheckPendingResponseBody>()
-                    .await
-                    .unwrap();
+                let ServiceCheckPendingResponseBody { history_id, ulid } =
+                    response.json::<ServiceCheckPendingResponseBody>().await?;
                 Err(ServiceCheckError::RequestPending { history_id, ulid })
             }
             StatusCode::FORBIDDEN => Err(ServiceCheckError::MissingApiKey),
             StatusCode::UNAUTHORIZED => {
                 let ServiceCheckInvalidApiKeyResponseBody { detail } = response
                     .json::<ServiceCheckInvalidApiKeyResponseBody>()
-                    .await
-                    .unwrap();
+                    .await?;
                 Err(ServiceCheckError::InvalidApiKey { detail })
             }
             StatusCode::NOT_FOUND => Err(ServiceCheckError::InvalidServiceID),
@@ -0,0 +1,223 @@
+use core::ops::Range;
+
+use crate::wrapper::{Imei, ImeiSv, Tac};
+
+/// Options for [`find_identifiers`], controlling which identifiers are reported and in which context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FindOptions<'k> {
+    /// Report 16-digit IMEISVs in addition to IMEIs.
+    pub include_imeisv: bool,
+    /// Report standalone 8-digit TACs in addition to IMEIs.
+    pub include_tac: bool,
+    /// Only report identifiers preceded by this keyword (compared case-insensitively), such as `"IMEI"`.
+    pub keyword: Option<&'k str>,
+    /// The maximum number of bytes allowed between the end of the keyword and the start of the identifier.
+    /// A keyword is never shared between two identifiers, so it must also appear after the previous identifier.
+    pub keyword_distance: usize,
+}
+
+/// An identifier found in free text by [`find_imeis`] or [`find_identifiers`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchedIdentifier {
+    Imei(Imei),
+    ImeiSv(ImeiSv),
+    Tac(Tac),
+}
+
+/// An identifier found in free text, along with its location in the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifierMatch {
+    /// The byte offset of the first digit of the identifier.
+    pub start: usize,
+    /// The byte offset just past the last digit of the identifier.
+    pub end: usize,
+    pub identifier: MatchedIdentifier,
+}
+
+/// An iterator over the identifiers found in free text, created by [`find_imeis`] or [`find_identifiers`].
+#[derive(Debug, Clone)]
+pub struct IdentifierMatches<'t, 'k> {
+    text: &'t str,
+    position: usize,
+    previous_end: usize,
+    options: FindOptions<'k>,
+}
+
+impl Default for FindOptions<'_> {
+    fn default() -> Self {
+        Self {
+            include_imeisv: false,
+            include_tac: false,
+            keyword: None,
+            keyword_distance: 24,
+        }
+    }
+}
+
+impl IdentifierMatch {
+    /// Retrieve the byte range of the identifier in the text it was found in.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Find every valid IMEI in free text, such as an email, a chat transcript, or a log file.
+///
+/// IMEIs may be written as a run of 15 digits or in common grouped forms like `35-674108-972868-6` or
+/// `35 674108 972868 6`, where the groups are separated by single spaces, dashes, dots, or slashes.
+/// Digit runs which are directly attached to letters or other digits are ignored, as are runs which
+/// fail the Luhn check.
+pub fn find_imeis(text: &str) -> IdentifierMatches<'_, 'static> {
+    find_identifiers(text, FindOptions::default())
+}
+
+/// Find every valid IMEI in free text, as well as IMEISVs and TACs if the options allow for them.
+/// See [`find_imeis`] for the accepted formats.
+pub fn find_identifiers<'t, 'k>(
+    text: &'t str,
+    options: FindOptions<'k>,
+) -> IdentifierMatches<'t, 'k> {
+    IdentifierMatches {
+        text,
+        position: 0,
+        previous_end: 0,
+        options,
+    }
+}
+
+impl IdentifierMatches<'_, '_> {
+    /// Try to find an identifier starting at the given position, which must be the first digit of a run.
+    /// Returns the match, if any, and the position where scanning should continue.
+    fn match_at(&self, start: usize) -> (Option<IdentifierMatch>, usize) {
+        let bytes = self.text.as_bytes();
+        let first_group_end = digits_end(bytes, start);
+
+        let mut digit_count = 0;
+        let mut candidates = [None; 3];
+        let mut group_start = start;
+        loop {
+            let group_end = digits_end(bytes, group_start);
+            digit_count += group_end - group_start;
+            if digit_count > 16 {
+                break;
+            }
+
+            let is_run_end = !continues_group(bytes, group_end);
+            let candidate = match digit_count {
+                15 => Some((0, group_end)),
+                16 => Some((1, group_end)),
+                8 if is_run_end => Some((2, group_end)),
+                _ => None,
+            };
+
+            if let Some((priority, end)) = candidate {
+                if !bytes.get(end).is_some_and(u8::is_ascii_alphabetic) {
+                    candidates[priority] = Some(end);
+                }
+            }
+
+            if is_run_end {
+                break;
+            }
+
+            group_start = group_end + 1;
+        }
+
+        let text = self.text;
+        let identifier = |end: usize| {
+            let candidate = &text[start..end];
+            if let Ok(imei) = Imei::parse_lenient(candidate) {
+                Some(MatchedIdentifier::Imei(imei))
+            } else if let Ok(imeisv) = ImeiSv::parse_lenient(candidate) {
+                Some(MatchedIdentifier::ImeiSv(imeisv))
+            } else {
+                Tac::parse_lenient(candidate)
+                    .ok()
+                    .map(MatchedIdentifier::Tac)
+            }
+        };
+
+        let found = [
+            (true, candidates[0]),
+            (self.options.include_imeisv, candidates[1]),
+            (self.options.include_tac, candidates[2]),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, end)| end.filter(|_| enabled))
+        .find_map(|end| {
+            identifier(end).map(|identifier| IdentifierMatch {
+                start,
+                end,
+                identifier,
+            })
+        });
+
+        match found {
+            Some(found) => {
+                let end = found.end;
+                (Some(found), end)
+            }
+            None => (None, first_group_end),
+        }
+    }
+
+    fn has_keyword_before(&self, start: usize) -> bool {
+        let Some(keyword) = self.options.keyword else {
+            return true;
+        };
+
+        let keyword = keyword.as_bytes();
+        let window_start = start
+            .saturating_sub(self.options.keyword_distance + keyword.len())
+            .max(self.previous_end);
+        self.text.as_bytes()[window_start..start]
+            .windows(keyword.len())
+            .any(|window| window.eq_ignore_ascii_case(keyword))
+    }
+}
+
+impl Iterator for IdentifierMatches<'_, '_> {
+    type Item = IdentifierMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        while self.position < bytes.len() {
+            let start = self.position;
+            if !bytes[start].is_ascii_digit() {
+                self.position += 1;
+                continue;
+            }
+
+            if start > 0 && bytes[start - 1].is_ascii_alphanumeric() {
+                self.position = digits_end(bytes, start);
+                continue;
+            }
+
+            let (found, next_position) = self.match_at(start);
+            self.position = next_position;
+            if let Some(found) = found {
+                let has_keyword = self.has_keyword_before(found.start);
+                self.previous_end = found.end;
+                if has_keyword {
+                    return Some(found);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Find the end of the run of digits starting at the given position.
+fn digits_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(bytes.len(), |length| start + length)
+}
+
+/// Check whether the group of digits ending at the given position is followed by a single separator and another group.
+fn continues_group(bytes: &[u8], group_end: usize) -> bool {
+    matches!(bytes.get(group_end), Some(b' ' | b'-' | b'.' | b'/'))
+        && bytes.get(group_end + 1).is_some_and(u8::is_ascii_digit)
+}
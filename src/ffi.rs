@@ -0,0 +1,453 @@
+//! C ABI for parsing and validating IMEIs, IMEISVs, and TACs.
+//!
+//! Every function reports its outcome as a [`Status`] and writes its result into caller-provided
+//! memory, so nothing is ever allocated on one side of the boundary and freed on the other.
+//! Strings are passed as a pointer and a byte length and do not need to be NUL-terminated.
+//! The C header for this module is generated by `cbindgen` and checked in at `include/imei_info.h`.
+
+use core::ffi::{c_char, c_int};
+use core::str::FromStr;
+
+use crate::wrapper::{luhn_checksum, Imei, ImeiSv, ImeiWrapperError, Tac};
+
+/// The outcome of an FFI call. Every variant other than `Ok` corresponds to an error, and any
+/// output parameters are left untouched when an error is returned.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    IncorrectLength = 2,
+    CannotParseDigits = 3,
+    ChecksumDoesNotMatch = 4,
+    ValueOutOfRange = 5,
+    BufferTooSmall = 6,
+}
+
+impl Status {
+    const ALL: [Status; 7] = [
+        Status::Ok,
+        Status::NullPointer,
+        Status::IncorrectLength,
+        Status::CannotParseDigits,
+        Status::ChecksumDoesNotMatch,
+        Status::ValueOutOfRange,
+        Status::BufferTooSmall,
+    ];
+
+    /// Convert a status code from C, which may be any integer, returning `None` if it is not a known status.
+    fn from_c_int(status: c_int) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|known| *known as c_int == status)
+    }
+}
+
+impl From<ImeiWrapperError> for Status {
+    fn from(error: ImeiWrapperError) -> Self {
+        match error {
+            ImeiWrapperError::ValueOutOfRange => Status::ValueOutOfRange,
+            ImeiWrapperError::IncorrectLength => Status::IncorrectLength,
+            ImeiWrapperError::CannotParseDigits => Status::CannotParseDigits,
+            ImeiWrapperError::ChecksumDoesNotMatch => Status::ChecksumDoesNotMatch,
+        }
+    }
+}
+
+/// Get a static, NUL-terminated description of a status code. The returned string must not be freed.
+/// The status is taken as an integer so that any value from C is safe to pass, and unknown values
+/// are described as such.
+#[no_mangle]
+pub extern "C" fn imei_info_status_message(status: c_int) -> *const c_char {
+    let Some(status) = Status::from_c_int(status) else {
+        return c"unknown status code".as_ptr();
+    };
+
+    match status {
+        Status::Ok => c"success",
+        Status::NullPointer => c"a required pointer argument was null",
+        Status::IncorrectLength => {
+            c"the input does not contain the required number of digits for an IMEI, IMEISV, or TAC"
+        }
+        Status::CannotParseDigits => c"one or more characters in the input is not numeric",
+        Status::ChecksumDoesNotMatch => c"the IMEI check digit does not match its Luhn checksum",
+        Status::ValueOutOfRange => {
+            c"provided numeric value is out of the required range for an IMEI or TAC"
+        }
+        Status::BufferTooSmall => c"the output buffer is too small for the result",
+    }
+    .as_ptr()
+}
+
+/// Compute the Luhn check digit for `len` digits (each in the range 0 to 9, not ASCII characters)
+/// and store it in `out`.
+///
+/// # Safety
+/// `digits` must point to `len` readable bytes and `out` must point to a writable byte.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_luhn_check_digit(
+    digits: *const u8,
+    len: usize,
+    out: *mut u8,
+) -> Status {
+    if digits.is_null() || out.is_null() {
+        return Status::NullPointer;
+    }
+
+    let digits = core::slice::from_raw_parts(digits, len);
+    if digits.iter().any(|d| *d > 9) {
+        return Status::ValueOutOfRange;
+    }
+
+    *out = luhn_checksum(digits);
+    Status::Ok
+}
+
+/// Compute the check digit for the first 14 digits of an IMEI, given as ASCII characters, and
+/// store it in `out` as a number in the range 0 to 9.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable byte.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_compute_check_digit(
+    input: *const c_char,
+    len: usize,
+    out: *mut u8,
+) -> Status {
+    if out.is_null() {
+        return Status::NullPointer;
+    }
+
+    let input = match input_str(input, len) {
+        Ok(input) => input,
+        Err(status) => return status,
+    };
+
+    let mut digits = [0u8; 14];
+    if input.len() != digits.len() {
+        return Status::IncorrectLength;
+    }
+
+    for (digit, c) in digits.iter_mut().zip(input.bytes()) {
+        if !c.is_ascii_digit() {
+            return Status::CannotParseDigits;
+        }
+
+        *digit = c - b'0';
+    }
+
+    *out = luhn_checksum(&digits);
+    Status::Ok
+}
+
+/// Convert an IMEISV to the IMEI of the same device, replacing the SVN with a computed check digit.
+///
+/// # Safety
+/// `imeisv` must point to a valid `ImeiInfoImeiSv` and `out` must point to a writable `ImeiInfoImei`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_to_imei(imeisv: *const ImeiSv, out: *mut Imei) -> Status {
+    if imeisv.is_null() || out.is_null() {
+        return Status::NullPointer;
+    }
+
+    out.write((*imeisv).imei());
+    Status::Ok
+}
+
+/// Extract the TAC from an IMEI.
+///
+/// # Safety
+/// `imei` must point to a valid `ImeiInfoImei` and `out` must point to a writable `ImeiInfoTac`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_to_tac(imei: *const Imei, out: *mut Tac) -> Status {
+    if imei.is_null() || out.is_null() {
+        return Status::NullPointer;
+    }
+
+    out.write(Tac {
+        digits: *(*imei).type_allocation_code(),
+    });
+    Status::Ok
+}
+
+/// Strictly parse an IMEI consisting of exactly the required number of ASCII digits and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImei`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_parse(
+    input: *const c_char,
+    len: usize,
+    out: *mut Imei,
+) -> Status {
+    parse(input, len, out, Imei::from_str)
+}
+
+/// Parse an IMEI which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImei`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_parse_lenient(
+    input: *const c_char,
+    len: usize,
+    out: *mut Imei,
+) -> Status {
+    parse(input, len, out, Imei::parse_lenient)
+}
+
+/// Check whether the input is a strictly formatted, valid IMEI without storing the result.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_validate(input: *const c_char, len: usize) -> Status {
+    validate(input, len, Imei::from_str)
+}
+
+/// Leniently parse an IMEI and write its canonical form (digits only) into `out` as a NUL-terminated string.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_normalize(
+    input: *const c_char,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    normalize(input, len, out, out_len, |s| {
+        Imei::parse_lenient(s).map(|value| value.digits)
+    })
+}
+
+/// Write the digits of an IMEI into `out` as a NUL-terminated string. If any digit is greater than 9,
+/// `ValueOutOfRange` is returned and nothing is written.
+///
+/// # Safety
+/// `value` must point to a valid `ImeiInfoImei` and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imei_format(
+    value: *const Imei,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    if value.is_null() {
+        return Status::NullPointer;
+    }
+
+    write_digits(&(*value).digits, out, out_len)
+}
+
+/// Strictly parse an IMEISV consisting of exactly the required number of ASCII digits and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImeiSv`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_parse(
+    input: *const c_char,
+    len: usize,
+    out: *mut ImeiSv,
+) -> Status {
+    parse(input, len, out, ImeiSv::from_str)
+}
+
+/// Parse an IMEISV which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoImeiSv`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_parse_lenient(
+    input: *const c_char,
+    len: usize,
+    out: *mut ImeiSv,
+) -> Status {
+    parse(input, len, out, ImeiSv::parse_lenient)
+}
+
+/// Check whether the input is a strictly formatted, valid IMEISV without storing the result.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_validate(input: *const c_char, len: usize) -> Status {
+    validate(input, len, ImeiSv::from_str)
+}
+
+/// Leniently parse an IMEISV and write its canonical form (digits only) into `out` as a NUL-terminated string.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_normalize(
+    input: *const c_char,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    normalize(input, len, out, out_len, |s| {
+        ImeiSv::parse_lenient(s).map(|value| value.digits)
+    })
+}
+
+/// Write the digits of an IMEISV into `out` as a NUL-terminated string. If any digit is greater than 9,
+/// `ValueOutOfRange` is returned and nothing is written.
+///
+/// # Safety
+/// `value` must point to a valid `ImeiInfoImeiSv` and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_imeisv_format(
+    value: *const ImeiSv,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    if value.is_null() {
+        return Status::NullPointer;
+    }
+
+    write_digits(&(*value).digits, out, out_len)
+}
+
+/// Strictly parse a TAC consisting of exactly the required number of ASCII digits and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoTac`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_tac_parse(
+    input: *const c_char,
+    len: usize,
+    out: *mut Tac,
+) -> Status {
+    parse(input, len, out, Tac::from_str)
+}
+
+/// Parse a TAC which may contain whitespace, dashes, dots, or slashes as separators and store it in `out`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to a writable `ImeiInfoTac`.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_tac_parse_lenient(
+    input: *const c_char,
+    len: usize,
+    out: *mut Tac,
+) -> Status {
+    parse(input, len, out, Tac::parse_lenient)
+}
+
+/// Check whether the input is a strictly formatted, valid TAC without storing the result.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_tac_validate(input: *const c_char, len: usize) -> Status {
+    validate(input, len, Tac::from_str)
+}
+
+/// Leniently parse a TAC and write its canonical form (digits only) into `out` as a NUL-terminated string.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_tac_normalize(
+    input: *const c_char,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    normalize(input, len, out, out_len, |s| {
+        Tac::parse_lenient(s).map(|value| value.digits)
+    })
+}
+
+/// Write the digits of a TAC into `out` as a NUL-terminated string. If any digit is greater than 9,
+/// `ValueOutOfRange` is returned and nothing is written.
+///
+/// # Safety
+/// `value` must point to a valid `ImeiInfoTac` and `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn imei_info_tac_format(
+    value: *const Tac,
+    out: *mut c_char,
+    out_len: usize,
+) -> Status {
+    if value.is_null() {
+        return Status::NullPointer;
+    }
+
+    write_digits(&(*value).digits, out, out_len)
+}
+
+unsafe fn input_str<'a>(input: *const c_char, len: usize) -> Result<&'a str, Status> {
+    if input.is_null() {
+        return Err(Status::NullPointer);
+    }
+
+    let bytes = core::slice::from_raw_parts(input.cast::<u8>(), len);
+    core::str::from_utf8(bytes).map_err(|_| Status::CannotParseDigits)
+}
+
+unsafe fn parse<T>(
+    input: *const c_char,
+    len: usize,
+    out: *mut T,
+    parser: fn(&str) -> Result<T, ImeiWrapperError>,
+) -> Status {
+    if out.is_null() {
+        return Status::NullPointer;
+    }
+
+    match input_str(input, len).and_then(|s| parser(s).map_err(Status::from)) {
+        Ok(value) => {
+            out.write(value);
+            Status::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe fn validate<T>(
+    input: *const c_char,
+    len: usize,
+    parser: fn(&str) -> Result<T, ImeiWrapperError>,
+) -> Status {
+    match input_str(input, len).and_then(|s| parser(s).map_err(Status::from)) {
+        Ok(_) => Status::Ok,
+        Err(status) => status,
+    }
+}
+
+unsafe fn normalize<const N: usize>(
+    input: *const c_char,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+    parser: fn(&str) -> Result<[u8; N], ImeiWrapperError>,
+) -> Status {
+    match input_str(input, len).and_then(|s| parser(s).map_err(Status::from)) {
+        Ok(digits) => write_digits(&digits, out, out_len),
+        Err(status) => status,
+    }
+}
+
+unsafe fn write_digits(digits: &[u8], out: *mut c_char, out_len: usize) -> Status {
+    if out.is_null() {
+        return Status::NullPointer;
+    }
+
+    // * The structs are writable from C, so their digits are not guaranteed to be in range
+    if digits.iter().any(|d| *d > 9) {
+        return Status::ValueOutOfRange;
+    }
+
+    if out_len <= digits.len() {
+        return Status::BufferTooSmall;
+    }
+
+    let out = core::slice::from_raw_parts_mut(out.cast::<u8>(), out_len);
+    for (c, digit) in out.iter_mut().zip(digits) {
+        *c = b'0' + digit;
+    }
+
+    out[digits.len()] = 0;
+    Status::Ok
+}
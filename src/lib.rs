@@ -1,8 +1,33 @@
+#![cfg_attr(not(any(feature = "client", feature = "ffi", test)), no_std)]
+
+mod anatomy;
+#[cfg(feature = "client")]
 mod api;
+mod bulk;
+mod device_output;
+#[cfg(feature = "client")]
 mod error;
+mod extract;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod mcc_mnc;
+mod mobile_identity;
+mod pairing;
+mod sanity;
+mod service;
 mod wrapper;
 
+pub use anatomy::*;
+pub use bulk::*;
+pub use device_output::*;
+#[cfg(feature = "client")]
 pub use error::*;
+pub use extract::*;
+pub use mcc_mnc::*;
+pub use mobile_identity::*;
+pub use pairing::*;
+pub use sanity::*;
+pub use service::*;
 pub use wrapper::*;
 
 #[cfg(test)]
@@ -141,6 +166,7 @@ mod tests {
         "351561163409762",
     ];
 
+    #[cfg(feature = "client")]
     #[tokio::test]
     #[ignore]
     async fn get_iphone_info() {
@@ -150,13 +176,14 @@ mod tests {
         assert_eq!(
             get_imei_info(&api_key, SAMPLE_IMEIS_IPHONE_X[0]).await,
             Ok(PhoneInfo {
-                imei: SAMPLE_IMEIS_IPHONE_X[0].try_into().unwrap(),
+                imeis: Imei::try_from(SAMPLE_IMEIS_IPHONE_X[0]).unwrap().into(),
                 manufacturer: "APPLE".to_owned(),
                 model: "iPhone X".to_owned(),
             })
         );
     }
 
+    #[cfg(feature = "client")]
     #[tokio::test]
     #[ignore]
     async fn valid_tac_check() {
@@ -167,7 +194,7 @@ mod tests {
         assert_eq!(
             get_tac_info(&api_key, tac).await,
             Ok(PhoneInfo {
-                imei: Tac::from_str(tac).unwrap().into(),
+                imeis: Imei::from(Tac::from_str(tac).unwrap()).into(),
                 manufacturer: "SAMSUNG".to_owned(),
                 model: "Galaxy S10 Exynos".to_owned(),
             })
@@ -252,4 +279,1629 @@ mod tests {
         try_bad_tac!(-1; i32, i64, i128, isize);
         try_bad_tac!(123456789; i32, u32, i64, u64, i128, u128, isize, usize);
     }
+
+    #[test]
+    fn try_bad_imei_from_string() {
+        assert_eq!(
+            Imei::from_str("35674108972868"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Imei::from_str("3567410897286860"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Imei::from_str("35674108972868a"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            Imei::from_str("356741089728687"),
+            Err(ImeiWrapperError::ChecksumDoesNotMatch)
+        );
+    }
+
+    #[test]
+    fn try_lenient_parsing() {
+        let imei = Imei::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap();
+        assert_eq!(
+            Imei::parse_lenient(" 35-674108-972868-6 "),
+            Ok(imei.clone())
+        );
+        assert_eq!(Imei::parse_lenient("35 674108 972868 6"), Ok(imei));
+        assert_eq!(
+            Imei::parse_lenient("35_674108_972868_6"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            Tac::parse_lenient("35/674108").unwrap().to_string(),
+            "35674108"
+        );
+    }
+
+    #[test]
+    fn imeisv_to_imei() {
+        let imeisv = ImeiSv::from_str("3567410897286801").unwrap();
+        assert_eq!(imeisv.software_version_number(), &[0, 1]);
+        assert_eq!(imeisv.imei().to_string(), SAMPLE_IMEIS_IPHONE_X[0]);
+        assert_eq!(u64::from(imeisv), 3567410897286801);
+    }
+
+    #[test]
+    fn luhn_checksums_of_any_length() {
+        // * IMEIs without their check digit have an even number of digits
+        assert_eq!(
+            luhn_checksum(&[3, 5, 6, 7, 4, 1, 0, 8, 9, 7, 2, 8, 6, 8]),
+            6
+        );
+        // * Doubling starts from the rightmost digit, so odd-length inputs use the same positions as even-length ones
+        assert_eq!(luhn_checksum(&[1, 2, 3, 4, 5, 6, 7]), 4);
+        assert_eq!(luhn_checksum(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1]), 3);
+        assert_eq!(luhn_checksum(&[]), 0);
+    }
+
+    #[test]
+    fn imei_from_ascii_digits_matches_from_str() {
+        let samples = SAMPLE_IMEIS_IPHONE_X
+            .iter()
+            .chain(&SAMPLE_IMEIS_SAMSUNG_S22)
+            .copied()
+            .chain([
+                "356741089728687",
+                "35674108972868a",
+                "35674108972868",
+                "3567410897286860",
+            ])
+            .chain(["/56741089728686", ":56741089728686", "3567410897286\u{e9}"]);
+
+        for sample in samples {
+            assert_eq!(
+                Imei::from_ascii_digits(sample.as_bytes()),
+                Imei::from_str(sample),
+                "{sample}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_bulk_lines() {
+        let buffer = b"356741089728686\r\n\n356741089728687\n  351725105350612  \nnot an imei\n";
+        let records: Vec<_> = validate_bulk(buffer, RecordFormat::Lines).collect();
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0].line, 1);
+        assert_eq!(records[0].result, Imei::from_str("356741089728686"));
+        assert_eq!(records[1].line, 3);
+        assert_eq!(
+            records[1].result,
+            Err(ImeiWrapperError::ChecksumDoesNotMatch)
+        );
+        assert_eq!(records[2].field, b"351725105350612");
+        assert_eq!(records[3].result, Err(ImeiWrapperError::IncorrectLength));
+
+        assert_eq!(
+            validate_bulk(buffer, RecordFormat::Lines).summary(),
+            BulkSummary {
+                records: 4,
+                valid: 2,
+                incorrect_length: 1,
+                cannot_parse_digits: 0,
+                checksum_does_not_match: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_bulk_csv() {
+        let buffer =
+            b"id,imei,model\n1,\"356741089728686\",iPhone X\n2,35674108972868a,iPhone X\n3\n";
+        let format = RecordFormat::Csv {
+            column: 1,
+            separator: b',',
+            has_header: true,
+        };
+        let records: Vec<_> = validate_bulk(buffer, format).collect();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].line, 2);
+        assert!(records[0].result.is_ok());
+        assert_eq!(records[1].result, Err(ImeiWrapperError::CannotParseDigits));
+        assert_eq!(records[2].field, b"");
+        assert_eq!(records[2].result, Err(ImeiWrapperError::IncorrectLength));
+    }
+
+    #[test]
+    fn find_imeis_in_text() {
+        let text = "Hi, my IMEI is 35-674108-972868-6 (the old one was 356741088901532). \
+                    Order 356741089728687 and ticket 1234567890123456789 are unrelated, \
+                    and neither is SN356741086755328. Other: 351725105350612, 351725107128370.";
+        let found: Vec<_> = find_imeis(text).collect();
+
+        assert_eq!(
+            found.iter().map(|m| &text[m.range()]).collect::<Vec<_>>(),
+            [
+                "35-674108-972868-6",
+                "356741088901532",
+                "351725105350612",
+                "351725107128370"
+            ]
+        );
+        assert_eq!(
+            found[0].identifier,
+            MatchedIdentifier::Imei(Imei::from_str("356741089728686").unwrap())
+        );
+    }
+
+    #[test]
+    fn find_identifiers_with_options() {
+        let text = "IMEISV 35 674108 972868 01, TAC 35172510, IMEI: 351725105350612, \
+                    also 356741088901532";
+        let options = FindOptions {
+            include_imeisv: true,
+            include_tac: true,
+            ..Default::default()
+        };
+
+        let found: Vec<_> = find_identifiers(text, options)
+            .map(|m| m.identifier)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                MatchedIdentifier::ImeiSv(ImeiSv::from_str("3567410897286801").unwrap()),
+                MatchedIdentifier::Tac(Tac::from_str("35172510").unwrap()),
+                MatchedIdentifier::Imei(Imei::from_str("351725105350612").unwrap()),
+                MatchedIdentifier::Imei(Imei::from_str("356741088901532").unwrap()),
+            ]
+        );
+
+        let options = FindOptions {
+            keyword: Some("imei"),
+            ..Default::default()
+        };
+        let found: Vec<_> = find_identifiers(text, options).map(|m| m.start).collect();
+        assert_eq!(found, [text.find("351725105350612").unwrap()]);
+    }
+
+    macro_rules! device_output_fixture {
+        ( $name:literal ) => {
+            include_str!(concat!("../tests/fixtures/device_output/", $name, ".txt"))
+        };
+    }
+
+    #[test]
+    fn parse_at_transcripts() {
+        let imei = ReportedIdentity::Imei(Imei::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap());
+
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_cgsn")),
+            Ok(imei.clone())
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_cgsn_imei")),
+            Ok(imei)
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_cgsn_imeisv")),
+            Ok(ReportedIdentity::ImeiSv(
+                ImeiSv::from_str("3567410897286801").unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_cgsn_svn")),
+            Ok(ReportedIdentity::SoftwareVersion([0, 1]))
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_cgsn_error")),
+            Err(DeviceOutputError::CommandFailed)
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_gsn")),
+            Ok(ReportedIdentity::Imei(
+                Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_at_response(device_output_fixture!("at_egmr_slot1")),
+            Ok(ReportedIdentity::Imei(
+                Imei::from_str(SAMPLE_IMEIS_IPHONE_X[1]).unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_at_response("AT+CGSN\r\r\nOK\r\n"),
+            Err(DeviceOutputError::MissingIdentifier)
+        );
+    }
+
+    #[test]
+    fn parse_ati_transcripts() {
+        let identities: Vec<_> = parse_ati_response(device_output_fixture!("ati_sierra"))
+            .map(|identity| identity.unwrap())
+            .collect();
+        assert_eq!(
+            identities,
+            [
+                SlotIdentity {
+                    slot: 0,
+                    identity: ReportedIdentity::Imei(
+                        Imei::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap()
+                    ),
+                },
+                SlotIdentity {
+                    slot: 0,
+                    identity: ReportedIdentity::SoftwareVersion([1, 2]),
+                },
+            ]
+        );
+
+        let slots: Vec<_> = parse_ati_response(device_output_fixture!("ati_dual_sim"))
+            .map(|identity| identity.unwrap().slot)
+            .collect();
+        assert_eq!(slots, [0, 1]);
+    }
+
+    #[test]
+    fn parse_adb_transcripts() {
+        assert_eq!(
+            parse_service_call_parcel(device_output_fixture!(
+                "adb_service_call_iphonesubinfo_slot0"
+            )),
+            Ok(ReportedIdentity::Imei(
+                Imei::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_service_call_parcel(device_output_fixture!(
+                "adb_service_call_iphonesubinfo_slot1"
+            )),
+            Ok(ReportedIdentity::Imei(
+                Imei::from_str(SAMPLE_IMEIS_IPHONE_X[1]).unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_service_call_parcel(device_output_fixture!(
+                "adb_service_call_iphonesubinfo_imeisv"
+            )),
+            Ok(ReportedIdentity::ImeiSv(
+                ImeiSv::from_str("3567410897286801").unwrap()
+            ))
+        );
+        assert_eq!(
+            parse_service_call_parcel(device_output_fixture!(
+                "adb_service_call_iphonesubinfo_denied"
+            )),
+            Err(DeviceOutputError::ParcelException { code: -1 })
+        );
+        assert_eq!(
+            parse_service_call_parcel("Result: Parcel(00000000 ffffffff   '........')"),
+            Err(DeviceOutputError::MissingIdentifier)
+        );
+
+        let identities: Vec<_> =
+            parse_dumpsys_iphonesubinfo(device_output_fixture!("dumpsys_iphonesubinfo"))
+                .map(|identity| identity.unwrap())
+                .collect();
+        assert_eq!(identities.len(), 2);
+        assert_eq!(identities[1].slot, 1);
+        assert_eq!(
+            identities[1].identity,
+            ReportedIdentity::Imei(Imei::from_str(SAMPLE_IMEIS_IPHONE_X[1]).unwrap())
+        );
+    }
+
+    #[test]
+    fn mobile_identity_round_trip() {
+        // * TS 24.008 10.5.1.4: first digit, odd indicator and type 010 in octet 3, spare digit sent as zero
+        let imei = Imei::from_str("356741089728686").unwrap();
+        let encoded = [0x3A, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x08];
+        assert_eq!(imei.to_mobile_identity(), encoded);
+        assert_eq!(Imei::from_mobile_identity(&encoded), Ok(imei.clone()));
+
+        // * A check digit in place of the spare digit is accepted if it matches
+        let with_check_digit = [0x3A, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x68];
+        assert_eq!(
+            Imei::from_mobile_identity(&with_check_digit),
+            Ok(imei.clone())
+        );
+        let wrong_check_digit = [0x3A, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x58];
+        assert_eq!(
+            Imei::from_mobile_identity(&wrong_check_digit),
+            Err(MobileIdentityError::ChecksumDoesNotMatch)
+        );
+
+        // * Even number of digits and type 011, with the 0xF filler in the final octet
+        let imeisv = ImeiSv::from_str("3567410897286801").unwrap();
+        let encoded = [0x33, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x08, 0xF1];
+        assert_eq!(imeisv.to_mobile_identity(), encoded);
+        assert_eq!(ImeiSv::from_mobile_identity(&encoded), Ok(imeisv.clone()));
+        assert_eq!(
+            decode_mobile_identity(&encoded),
+            Ok(MobileIdentity::ImeiSv(imeisv.clone()))
+        );
+
+        assert_eq!(
+            imei.to_tbcd(),
+            [0x53, 0x76, 0x14, 0x80, 0x79, 0x82, 0x86, 0xF0]
+        );
+        assert_eq!(Imei::from_tbcd(&imei.to_tbcd()), Ok(imei.clone()));
+        assert_eq!(
+            imeisv.to_tbcd(),
+            [0x53, 0x76, 0x14, 0x80, 0x79, 0x82, 0x86, 0x10]
+        );
+        assert_eq!(ImeiSv::from_tbcd(&imeisv.to_tbcd()), Ok(imeisv.clone()));
+
+        for imei in SAMPLE_IMEIS_IPHONE_X.iter().chain(&SAMPLE_IMEIS_IPHONE_11) {
+            let imei = Imei::from_str(imei).unwrap();
+            assert_eq!(
+                Imei::from_mobile_identity(&imei.to_mobile_identity()),
+                Ok(imei.clone())
+            );
+            assert_eq!(Imei::from_tbcd(&imei.to_tbcd()), Ok(imei));
+        }
+
+        assert_eq!(
+            Imei::from_terminal_information("35674108972868"),
+            Ok(imei.clone())
+        );
+        assert_eq!(
+            ImeiSv::from_terminal_information("35674108972868", "01"),
+            Ok(imeisv)
+        );
+    }
+
+    #[test]
+    fn malformed_mobile_identity() {
+        let encoded = [0x3A, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x08];
+        assert_eq!(
+            Imei::from_mobile_identity(&encoded[..7]),
+            Err(MobileIdentityError::IncorrectLength)
+        );
+        assert_eq!(
+            Imei::from_mobile_identity(&[]),
+            Err(MobileIdentityError::IncorrectLength)
+        );
+
+        let mut invalid_digit = encoded;
+        invalid_digit[3] = 0x0C;
+        assert_eq!(
+            Imei::from_mobile_identity(&invalid_digit),
+            Err(MobileIdentityError::InvalidDigit)
+        );
+
+        let mut even = encoded;
+        even[0] = 0x32;
+        assert_eq!(
+            Imei::from_mobile_identity(&even),
+            Err(MobileIdentityError::OddEvenMismatch)
+        );
+
+        // * Type of identity 001 is an IMSI
+        let mut imsi = encoded;
+        imsi[0] = 0x39;
+        assert_eq!(
+            decode_mobile_identity(&imsi),
+            Err(MobileIdentityError::UnexpectedIdentityType { identity_type: 1 })
+        );
+        assert_eq!(
+            ImeiSv::from_mobile_identity(&encoded),
+            Err(MobileIdentityError::UnexpectedIdentityType { identity_type: 2 })
+        );
+
+        let mut missing_filler = [0x33, 0x65, 0x47, 0x01, 0x98, 0x27, 0x68, 0x08, 0xF1];
+        missing_filler[8] = 0x01;
+        assert_eq!(
+            ImeiSv::from_mobile_identity(&missing_filler),
+            Err(MobileIdentityError::InvalidFiller)
+        );
+        assert_eq!(
+            Imei::from_tbcd(&[0x53, 0x76, 0x14, 0x80, 0x79, 0x82, 0x86, 0x00]),
+            Err(MobileIdentityError::InvalidFiller)
+        );
+        assert_eq!(
+            Imei::from_terminal_information("3567410897286"),
+            Err(MobileIdentityError::IncorrectLength)
+        );
+    }
+
+    #[test]
+    fn parse_iccids() {
+        let iccid = Iccid::from_str("89441000001234567897").unwrap();
+        assert_eq!(iccid.major_industry_identifier(), &[8, 9]);
+        assert_eq!(iccid.country_code(), &[4, 4]);
+        assert_eq!(iccid.issuer_identifier(), &[1, 0, 0]);
+        assert_eq!(iccid.issuer_identification_number(), &[8, 9, 4, 4, 1, 0, 0]);
+        assert_eq!(
+            iccid.account_number(),
+            &[0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+        assert_eq!(iccid.check_digit(), 7);
+        assert_eq!(iccid.to_string(), "89441000001234567897");
+
+        // * 19-digit ICCIDs are also accepted, and North American issuers have a one-digit country code
+        let iccid = Iccid::from_str("8910042348144559361").unwrap();
+        assert_eq!(iccid.country_code(), &[1]);
+        assert_eq!(iccid.issuer_identifier(), &[0, 0, 4, 2]);
+        assert_eq!(iccid.digits().len(), 19);
+
+        assert_eq!(
+            Iccid::from_str("89441000001234567898"),
+            Err(ImeiWrapperError::ChecksumDoesNotMatch)
+        );
+        assert_eq!(
+            Iccid::from_str("894410000012345678"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Iccid::from_str("894410000012345678971"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Iccid::from_str("8944100000123456789F"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+    }
+
+    #[test]
+    fn parse_eids() {
+        let eid = Eid::from_str("89049032004008882600000012345654").unwrap();
+        assert_eq!(eid.major_industry_identifier(), &[8, 9]);
+        assert_eq!(eid.country_code(), &[0, 4, 9]);
+        assert_eq!(eid.issuer_identifier(), &[0, 3, 2]);
+        assert_eq!(eid.version_information(), &[0, 0, 4, 0, 0]);
+        assert_eq!(eid.additional_issuer_information(), &[8, 8, 8, 2, 6]);
+        assert_eq!(
+            eid.individual_identification_number(),
+            &[0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(eid.check_digits(), &[5, 4]);
+
+        assert_eq!(
+            Eid::from_str("89049032004008882600000012345655"),
+            Err(ImeiWrapperError::ChecksumDoesNotMatch)
+        );
+        assert_eq!(
+            Eid::from_str("8904903200400888260000001234565"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+
+        assert_eq!(
+            EsimIdentifier::from_str("89049032004008882600000012345654"),
+            Ok(EsimIdentifier::Eid(eid.clone()))
+        );
+        assert_eq!(
+            EsimIdentifier::from(Iccid::from_str("89441000001234567897").unwrap()).to_string(),
+            "89441000001234567897"
+        );
+    }
+
+    #[test]
+    fn parse_imsis() {
+        // * The MNC length comes from the operator table where possible, and otherwise from the country
+        let imsi = Imsi::from_str("310260123456789").unwrap();
+        assert_eq!(imsi.mobile_country_code(), &[3, 1, 0]);
+        assert_eq!(imsi.mobile_network_code(), &[2, 6, 0]);
+        assert_eq!(imsi.msin(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(imsi.country().unwrap().iso_code, "US");
+        assert_eq!(imsi.operator().unwrap().name, "T-Mobile");
+
+        let imsi = Imsi::from_str("234150123456789").unwrap();
+        assert_eq!(imsi.mobile_network_code(), &[1, 5]);
+        assert_eq!(imsi.operator().unwrap().name, "Vodafone");
+        assert_eq!(imsi.country().unwrap().name, "United Kingdom");
+
+        let imsi = Imsi::from_str("405857123456789").unwrap();
+        assert_eq!(imsi.mobile_network_code(), &[8, 5, 7]);
+        assert_eq!(imsi.operator().unwrap().name, "Jio");
+
+        let imsi = Imsi::from_str("311999123456789").unwrap();
+        assert_eq!(imsi.mobile_network_code(), &[9, 9, 9]);
+        assert_eq!(imsi.operator(), None);
+        let imsi = Imsi::from_str("262991234567890").unwrap();
+        assert_eq!(imsi.mobile_network_code(), &[9, 9]);
+
+        let imsi = Imsi::with_mnc_length("262991234567890", 3).unwrap();
+        assert_eq!(imsi.mobile_network_code(), &[9, 9, 1]);
+        assert_eq!(imsi.to_string(), "262991234567890");
+
+        assert_eq!(
+            Imsi::from_str("0010112345678901"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Imsi::from_str("00101"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Imsi::with_mnc_length("001011234567890", 4),
+            Err(ImeiWrapperError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Imsi::from_str("00101123456789A"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+    }
+
+    #[test]
+    fn parse_msisdns() {
+        let msisdn = Msisdn::from_str("+447700900123").unwrap();
+        assert_eq!(msisdn.country_code(), &[4, 4]);
+        assert_eq!(msisdn.national_number(), &[7, 7, 0, 0, 9, 0, 0, 1, 2, 3]);
+        assert_eq!(
+            msisdn
+                .countries()
+                .map(|country| country.iso_code)
+                .collect::<Vec<_>>(),
+            ["GB"]
+        );
+        assert_eq!(msisdn.to_string(), "+447700900123");
+        assert_eq!(Msisdn::from_str("447700900123"), Ok(msisdn));
+
+        let msisdn = Msisdn::parse_lenient("+1 (202) 555-0123").unwrap();
+        assert_eq!(msisdn.country_code(), &[1]);
+        assert!(msisdn.countries().any(|country| country.iso_code == "US"));
+        assert!(msisdn.countries().any(|country| country.iso_code == "CA"));
+
+        let msisdn = Msisdn::from_str("+971501234567").unwrap();
+        assert_eq!(msisdn.country_code(), &[9, 7, 1]);
+        assert_eq!(
+            msisdn.countries().next().unwrap().name,
+            "United Arab Emirates"
+        );
+
+        assert_eq!(
+            Msisdn::from_str("+0447700900123"),
+            Err(ImeiWrapperError::ValueOutOfRange)
+        );
+        assert_eq!(
+            Msisdn::from_str("+4477009001234567"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            Msisdn::from_str("+44 7700 900123"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+    }
+
+    #[test]
+    fn find_operators_by_name() {
+        assert_eq!(
+            find_operator_by_name("T-Mobile USA", Some("US")),
+            find_operator("310", "260")
+        );
+        assert_eq!(
+            find_operator_by_name("t-mobile", Some("PL")),
+            find_operator("260", "02")
+        );
+        assert_eq!(
+            find_operator_by_name("AT&T Wireless", None).unwrap().name,
+            "AT&T"
+        );
+        assert_eq!(find_operator_by_name("Nonexistent Mobile", None), None);
+        assert_eq!(
+            find_operator_by_name("Verizon Wireless", None),
+            find_operator("310", "004")
+        );
+        assert_eq!(
+            find_operator_by_name("T-Mobile/MetroPCS", Some("US")),
+            find_operator("310", "260")
+        );
+        assert_eq!(
+            find_operator_by_name("AT&T Mobility", None).unwrap().name,
+            "AT&T"
+        );
+        assert_eq!(
+            find_operator_by_name("E&", None),
+            find_operator("424", "02")
+        );
+        assert_eq!(
+            find_operator_by_name("du.", None),
+            find_operator("424", "03")
+        );
+
+        // * Names from the table must not match the start of a word, and short names must match exactly
+        assert_eq!(find_operator_by_name("Entel", None), None);
+        assert_eq!(find_operator_by_name("Eir", None), None);
+        assert_eq!(find_operator_by_name("Dutch Telecom", None), None);
+        assert_eq!(find_operator_by_name("Auchan Telecom", None), None);
+        assert_eq!(find_operator_by_name("au Mobile", None), None);
+        assert_eq!(find_operator_by_name("Verizonia", None), None);
+        assert_eq!(find_country_by_mcc("724").unwrap().name, "Brazil");
+    }
+
+    #[test]
+    fn decode_apple_serials() {
+        let serial = AppleSerial::from_str("c02xk0wxjg5j").unwrap();
+        assert_eq!(serial.as_str(), "C02XK0WXJG5J");
+        assert_eq!(serial.format(), AppleSerialFormat::Legacy12);
+        assert_eq!(serial.manufacturing_location(), Some("C02"));
+        assert_eq!(serial.factory(), Some("Quanta Computer, Shanghai, China"));
+        assert_eq!(
+            serial.manufacturing_week(),
+            Some(ManufacturingWeek {
+                year: 2018,
+                half: 2,
+                week: 42
+            })
+        );
+        assert_eq!(serial.unit_id(), Some("0WX"));
+        assert_eq!(serial.model_code(), Some("JG5J"));
+
+        let serial = AppleSerial::from_str("F5KC1ABCDFR9").unwrap();
+        assert_eq!(serial.factory(), Some("Foxconn, Zhengzhou, China"));
+        assert_eq!(
+            serial.manufacturing_week(),
+            Some(ManufacturingWeek {
+                year: 2010,
+                half: 1,
+                week: 1
+            })
+        );
+
+        let serial = AppleSerial::from_str("W8035ABCY51").unwrap();
+        assert_eq!(serial.format(), AppleSerialFormat::Legacy11);
+        assert_eq!(serial.factory(), Some("Shanghai, China"));
+        assert_eq!(
+            serial.manufacturing_week(),
+            Some(ManufacturingWeek {
+                year: 2010,
+                half: 2,
+                week: 35
+            })
+        );
+        assert_eq!(serial.unit_id(), Some("ABC"));
+        assert_eq!(serial.model_code(), Some("Y51"));
+
+        let serial = AppleSerial::from_str("H4C2N3F7P1").unwrap();
+        assert_eq!(serial.format(), AppleSerialFormat::Randomized);
+        assert_eq!(serial.manufacturing_location(), None);
+        assert_eq!(serial.manufacturing_week(), None);
+        assert_eq!(serial.model_code(), None);
+
+        // * 12-character serials cannot use `A` for the year or `0` for the week
+        assert_eq!(
+            AppleSerial::from_str("C02AK0WXJG5J"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            AppleSerial::from_str("C02X00WXJG5J"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            AppleSerial::from_str("W8054ABCY51"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            AppleSerial::from_str("H4C2N3F7P"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        assert_eq!(
+            AppleSerial::from_str("H4C2N-F7P1"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+
+        assert_eq!(
+            DeviceIdentifier::from_str("356741089728686"),
+            Ok(DeviceIdentifier::Imei(
+                Imei::from_str("356741089728686").unwrap()
+            ))
+        );
+        assert_eq!(
+            DeviceIdentifier::from_str("C02XK0WXJG5J")
+                .unwrap()
+                .to_string(),
+            "C02XK0WXJG5J"
+        );
+        assert_eq!(
+            DeviceIdentifier::from_str("356741089728687"),
+            Err(ImeiWrapperError::ChecksumDoesNotMatch)
+        );
+    }
+
+    #[test]
+    fn parse_mac_addresses() {
+        let mac = MacAddress::from_str("3c:07:54:12:ab:CD").unwrap();
+        assert_eq!(mac.octets, [0x3C, 0x07, 0x54, 0x12, 0xAB, 0xCD]);
+        assert_eq!(mac.oui(), &[0x3C, 0x07, 0x54]);
+        assert_eq!(mac.network_interface_controller(), &[0x12, 0xAB, 0xCD]);
+        assert_eq!(mac.to_string(), "3C:07:54:12:AB:CD");
+        assert!(!mac.is_locally_administered());
+        assert!(!mac.is_multicast());
+        for form in ["3C-07-54-12-AB-CD", "3c07.5412.abcd", "3C075412ABCD"] {
+            assert_eq!(MacAddress::from_str(form), Ok(mac));
+        }
+
+        let randomized = MacAddress::from_str("DA:A1:19:00:00:01").unwrap();
+        assert!(randomized.is_locally_administered());
+        let multicast = MacAddress::from_str("01:00:5E:00:00:FB").unwrap();
+        assert!(multicast.is_multicast());
+
+        #[cfg(feature = "oui")]
+        {
+            assert_eq!(mac.vendor(), Some("Apple"));
+            assert_eq!(randomized.vendor(), None);
+        }
+
+        assert_eq!(
+            MacAddress::from_str("3C:07-54:12:AB:CD"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            MacAddress::from_str("3C:07:54:12:AB:CG"),
+            Err(ImeiWrapperError::CannotParseDigits)
+        );
+        assert_eq!(
+            MacAddress::from_str("3C:07:54:12:AB"),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+    }
+
+    #[test]
+    fn explain_imeis() {
+        let anatomy = Imei::from_str("356741089728686").unwrap().explain();
+        assert_eq!(anatomy.reporting_body_name, Some("BABT (United Kingdom)"));
+        assert_eq!(anatomy.type_allocation_code, [3, 5, 6, 7, 4, 1, 0, 8]);
+        assert_eq!(anatomy.legacy, None);
+        assert!(anatomy.is_check_digit_valid());
+        assert_eq!(
+            anatomy.to_string(),
+            "Reporting body: 35 (BABT (United Kingdom))\n\
+             Model identifier: 674108 (Forms the type allocation code with the reporting body)\n\
+             Serial number: 972868\n\
+             Check digit: 6 (Valid)"
+        );
+
+        // * Reporting body 44 only allocated codes before 2004, so digits 7 and 8 are a final assembly code
+        let anatomy = Imei::from_str("449123456789014").unwrap().explain();
+        assert_eq!(
+            anatomy.legacy,
+            Some(LegacyAllocation {
+                type_approval_code: [4, 4, 9, 1, 2, 3],
+                final_assembly_code: [4, 5],
+            })
+        );
+        let labels: Vec<_> = anatomy.segments().map(|segment| segment.label).collect();
+        assert_eq!(
+            labels,
+            [
+                "Reporting body",
+                "Type approval code",
+                "Final assembly code",
+                "Serial number",
+                "Check digit"
+            ]
+        );
+
+        let mut invalid = Imei::from_str("356741089728686").unwrap();
+        invalid.digits[14] = 7;
+        let anatomy = invalid.explain();
+        assert!(!anatomy.is_check_digit_valid());
+        assert_eq!(anatomy.expected_check_digit, 6);
+
+        let anatomy = ImeiSv::from_str("3567410897286801").unwrap().explain();
+        assert_eq!(anatomy.check_digit, None);
+        assert_eq!(anatomy.software_version_number, Some([0, 1]));
+        let last = anatomy.segments().last().unwrap();
+        assert_eq!(last.label, "Software version number");
+        assert_eq!(last.position, 14..16);
+        assert_eq!(last.digits, &[0, 1]);
+    }
+
+    #[test]
+    fn flag_suspicious_imeis() {
+        for imei in SAMPLE_IMEIS_IPHONE_X.iter().chain(&SAMPLE_IMEIS_IPHONE_11) {
+            assert!(Imei::from_str(imei).unwrap().sanity_flags().is_empty());
+        }
+
+        let flags = Imei::from_str("000000000000000").unwrap().sanity_flags();
+        assert!(flags.contains(SanityFlag::AllZeros));
+        assert!(flags.contains(SanityFlag::TestImei));
+        assert!(flags.contains(SanityFlag::ZeroSerialNumber));
+        assert!(!flags.contains(SanityFlag::RepeatedDigits));
+
+        let flags = Imei::from_str("001010004829139").unwrap().sanity_flags();
+        assert_eq!(flags, SanityFlags::from(SanityFlag::TestImei));
+
+        let flags = Imei::from_str("356741080000002").unwrap().sanity_flags();
+        assert_eq!(flags, SanityFlags::from(SanityFlag::ZeroSerialNumber));
+
+        let flags = Imei::from_str("353535353535352").unwrap().sanity_flags();
+        assert_eq!(flags, SanityFlags::from(SanityFlag::RepeatedDigits));
+
+        let flags = Imei::from_str("356741081234568").unwrap().sanity_flags();
+        assert_eq!(flags, SanityFlags::from(SanityFlag::SequentialDigits));
+
+        let flags = Imei::from_str("358688000000158").unwrap().sanity_flags();
+        assert!(flags.contains(SanityFlag::KnownPlaceholder));
+
+        let flags = Imei::from_str("721234567890127").unwrap().sanity_flags();
+        assert!(flags.contains(SanityFlag::UnknownReportingBody));
+        assert_eq!(
+            SanityFlags::from_iter([SanityFlag::ZeroSerialNumber, SanityFlag::TestImei])
+                .to_string(),
+            "the IMEI is reserved for test equipment; the serial number of the IMEI is zero"
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn classify_malformed_responses() {
+        let response = |status: u16, body: &'static str| {
+            reqwest::Response::from(http::Response::builder().status(status).body(body).unwrap())
+        };
+        let classify = |response| async {
+            ServiceCheckError::classify_response::<api::ApiPhoneInfo>(response)
+                .await
+                .err()
+        };
+
+        assert_eq!(
+            classify(response(
+                202,
+                r#"{"history_id": "1", "ulid": "01J5QTH0G0A1B2C3D4E5F6G7H0"}"#
+            ))
+            .await,
+            Some(ServiceCheckError::RequestPending {
+                history_id: "1".to_owned(),
+                ulid: "01J5QTH0G0A1B2C3D4E5F6G7H0".to_owned(),
+            })
+        );
+        // * Bodies which do not match are reported as errors rather than panicking
+        assert!(matches!(
+            classify(response(202, "<html></html>")).await,
+            Some(ServiceCheckError::UnknownRequestError { .. })
+        ));
+        assert!(matches!(
+            classify(response(401, "{}")).await,
+            Some(ServiceCheckError::UnknownRequestError { .. })
+        ));
+
+        // * A paid check whose result does not match keeps the whole body
+        let body = include_str!("../tests/fixtures/service_check/mac_address_check.json");
+        match classify(response(200, body)).await {
+            Some(ServiceCheckError::MalformedResult { body: json, .. }) => {
+                assert_eq!(
+                    json,
+                    serde_json::from_str::<serde_json::Value>(body).unwrap()
+                );
+            }
+            error => panic!("expected a malformed result, got {error:?}"),
+        }
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn refuse_flagged_imeis() {
+        let options = LookupOptions {
+            refuse_flags: SanityFlags::all(),
+        };
+        // * The IMEI is refused before the API key is used, so no request is made
+        assert_eq!(
+            get_imei_info_with_options("", "000000000000000", &options).await,
+            Err(ServiceCheckError::SuspiciousImei {
+                flags: Imei::from_str("000000000000000").unwrap().sanity_flags(),
+            })
+        );
+        let refused = |error: Option<ServiceCheckError>| {
+            matches!(error, Some(ServiceCheckError::SuspiciousImei { .. }))
+        };
+        assert!(refused(
+            get_blacklist_status_with_options(
+                "",
+                "000000000000000",
+                BlacklistTier::Premium,
+                &options
+            )
+            .await
+            .err()
+        ));
+        assert!(refused(
+            get_carrier_status_with_options("", "000000000000000", CarrierCheck::Lookup, &options)
+                .await
+                .err()
+        ));
+        assert!(refused(
+            get_manufacturer_info_with_options("", "000000000000000", &options)
+                .await
+                .err()
+        ));
+        assert!(refused(
+            get_apple_carrier_lock_fmi_status_with_options("", "000000000000000", &options)
+                .await
+                .err()
+        ));
+
+        // * TACs are only refused for flags which depend on the TAC, not for the zero serial number they generate
+        assert!(refused(
+            get_tac_info_with_options("", "00101000", &options)
+                .await
+                .err()
+        ));
+        let tac = Tac::from_str(&SAMPLE_IMEIS_SAMSUNG_S10[0][0..8]).unwrap();
+        assert!(Imei::from(tac.clone())
+            .sanity_flags()
+            .contains(SanityFlag::ZeroSerialNumber));
+        assert_eq!(options.refused_tac_flags(&Imei::from(tac)), None);
+    }
+
+    #[test]
+    fn check_imei_pairs() {
+        let primary = Imei::from_str("356741089728686").unwrap();
+        let pair = |secondary: &str| {
+            DeviceImeis::new(primary.clone(), Some(Imei::from_str(secondary).unwrap()))
+        };
+
+        assert_eq!(
+            DeviceImeis::from(primary.clone()).check_consistency(),
+            Ok(())
+        );
+        assert_eq!(pair("356741089728694").check_consistency(), Ok(()));
+        // * Adjacent TACs in the same family are accepted
+        assert_eq!(pair("356741099728700").check_consistency(), Ok(()));
+        assert_eq!(
+            pair("356741089728686").check_consistency(),
+            Err(ImeiPairMismatch::Identical)
+        );
+        assert_eq!(
+            pair("356741080856916").check_consistency(),
+            Err(ImeiPairMismatch::DistantSerialNumbers { distance: 887177 })
+        );
+        assert_eq!(
+            pair("356656424381449").check_consistency(),
+            Err(ImeiPairMismatch::DifferentTacFamily)
+        );
+        assert_eq!(
+            pair("356656424381449").check_consistency_with(&[(
+                Tac::from_str("35665642").unwrap(),
+                Tac::from_str("35674108").unwrap(),
+            )]),
+            Err(ImeiPairMismatch::DistantSerialNumbers { distance: 534724 })
+        );
+
+        let mut secondary = Imei::from_str("356741089728694").unwrap();
+        secondary.digits[14] = 5;
+        let invalid = DeviceImeis::new(primary.clone(), Some(secondary));
+        assert_eq!(
+            invalid.check_consistency(),
+            Err(ImeiPairMismatch::InvalidSecondary)
+        );
+        assert_eq!(invalid.iter().count(), 2);
+    }
+
+    #[test]
+    fn look_up_services() {
+        for service in Service::KNOWN {
+            assert_eq!(Service::from_id(service.id()), service);
+        }
+
+        // * The plain FMI check is left out until its ID is confirmed
+        assert_eq!(Service::from_id(1), Service::Custom(1));
+        assert_eq!(Service::from(106), Service::MacAddressCheck);
+        assert_eq!(Service::from(999), Service::Custom(999));
+        assert_eq!(u32::from(Service::Custom(999)), 999);
+        assert_eq!(Service::Custom(999).to_string(), "Custom Service (999)");
+        assert_eq!(Service::Custom(2), Service::AppleCarrierLockFmiStatus);
+        assert_eq!(
+            Service::Custom(2).to_string(),
+            Service::AppleCarrierLockFmiStatus.to_string()
+        );
+        assert_eq!(Service::Custom(2).brand(), Some(Brand::Apple));
+        assert!(Service::Custom(0).is_free());
+        assert_ne!(Service::Custom(999), Service::Custom(998));
+        assert_eq!(
+            std::collections::HashSet::from([Service::Custom(48), Service::CarrierLookup]).len(),
+            1
+        );
+        assert_eq!(Service::SamsungKnoxInfoCheck.brand(), Some(Brand::Samsung));
+        assert_eq!(Service::BlacklistPremiumCheck.brand(), None);
+        assert!(Service::AppleWarrantyCheck.accepts(IdentifierKind::SerialNumber));
+        assert!(!Service::BasicImeiCheck.accepts(IdentifierKind::MacAddress));
+        assert!(Service::BasicImeiCheck.is_free());
+        assert!(!Service::Custom(999).is_free());
+
+        assert_eq!(Brand::from_manufacturer("APPLE"), Some(Brand::Apple));
+        assert_eq!(Brand::from_manufacturer("LG Electronics"), Some(Brand::Lg));
+        assert_eq!(Brand::from_manufacturer("Lgbt Phones"), None);
+        assert_eq!(Brand::from_manufacturer("Nokia"), None);
+        assert_eq!(Brand::Sony.info_service(), Some(Service::SonyInfoCheck));
+        assert_eq!(Brand::Apple.info_service(), None);
+    }
+
+    #[cfg(feature = "client")]
+    macro_rules! service_check_fixture {
+        ( $name:literal ) => {
+            serde_json::from_str::<api::ServiceCheckStandardResponseBody<_>>(include_str!(concat!(
+                "../tests/fixtures/service_check/",
+                $name,
+                ".json"
+            )))
+            .unwrap()
+        };
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_service_results() {
+        #[derive(serde::Deserialize)]
+        struct BasicResult {
+            brand_name: String,
+        }
+
+        impl ServiceCheck for BasicResult {
+            const SERVICE: Service = Service::BasicImeiCheck;
+        }
+
+        let json = include_str!("../tests/fixtures/service_check/basic_imei_check.json");
+        let response: api::ServiceCheckStandardResponseBody<BasicResult> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(response.result.brand_name, "APPLE");
+        assert_eq!(Service::from(response.service_id), BasicResult::SERVICE);
+
+        let response: api::ServiceCheckStandardResponseBody<serde_json::Value> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(response.result["model"], "iPhone X");
+
+        let record = CheckRecord::from(response);
+        assert_eq!(record.service, Service::BasicImeiCheck);
+        assert_eq!(record.status, CheckStatus::Done);
+        assert_eq!(record.token_request_price.to_string(), "0.25");
+        assert_eq!(record.ulid_timestamp(), Some(record.created_at));
+        assert_eq!(record.imei2.as_deref(), Some("356741089728694"));
+
+        let response =
+            serde_json::from_str::<api::ServiceCheckStandardResponseBody<api::ApiPhoneInfo>>(json)
+                .unwrap();
+        let info = PhoneInfo::from_api(response.result, Some("356741089728694")).unwrap();
+        assert_eq!(info.imeis.secondary, Imei::from_str("356741089728694").ok());
+        let malformed = api::ApiPhoneInfo {
+            imei: String::new(),
+            brand_name: "APPLE".to_owned(),
+            model: "iPhone X".to_owned(),
+        };
+        assert_eq!(
+            PhoneInfo::from_api(malformed, None),
+            Err(ImeiWrapperError::IncorrectLength)
+        );
+        let debug = format!("{record:?}");
+        assert!(debug.contains("token_key: \"<redacted>\""));
+        assert!(!debug.contains("secret"));
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_mac_address_infos() {
+        let mac = MacAddress::from_str("3C:07:54:12:AB:CD").unwrap();
+        let response = service_check_fixture!("mac_address_check");
+        assert_eq!(response.service_id, Service::MacAddressCheck.id());
+        assert_eq!(
+            MacAddressInfo::from_api(mac, response.result),
+            MacAddressInfo {
+                mac_address: mac,
+                vendor: Some("Apple, Inc.".to_owned()),
+                address: Some("1 Infinite Loop, Cupertino CA 95014".to_owned()),
+                country: Some("US".to_owned()),
+                model: None,
+                device_type: None,
+            }
+        );
+
+        // * A result without the vendor is an unknown schema, which is reported as an error
+        assert!(
+            serde_json::from_str::<api::ApiMacAddressInfo>(r#"{"brand_name": "Apple"}"#).is_err()
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_apple_fmi_statuses() {
+        let response = service_check_fixture!("apple_carrier_lock_fmi_status");
+        let imei = DeviceIdentifier::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap();
+        let status = FmiStatus::from_api(imei, response.result);
+        assert_eq!(status.find_my_iphone, FmiState::Off);
+        assert_eq!(status.lost_mode, Some(false));
+        assert_eq!(
+            status.carrier_lock,
+            CarrierLock {
+                locked: Some(true),
+                carrier: Some("AT&T".to_owned()),
+                policy: Some("US AT&T Locked Policy".to_owned()),
+            }
+        );
+        assert!(!status.is_activation_locked());
+
+        // * The schema is not confirmed, so missing fields are unknown rather than failing a paid check
+        let result = serde_json::from_str(r#"{"model": "iPhone 11"}"#).unwrap();
+        let status = FmiStatus::from_api(
+            DeviceIdentifier::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap(),
+            result,
+        );
+        assert_eq!(status.find_my_iphone, FmiState::Unknown);
+        assert_eq!(status.carrier_lock.locked, None);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_apple_warranties() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let response = service_check_fixture!("apple_warranty");
+        let imei = DeviceIdentifier::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap();
+        let warranty = AppleWarranty::from_api(imei.clone(), response.result);
+        assert_eq!(
+            warranty,
+            AppleWarranty {
+                identifier: imei,
+                model: Some("iPhone 11".to_owned()),
+                purchase_date: NaiveDate::from_ymd_opt(2023, 9, 22),
+                warranty_start: NaiveDate::from_ymd_opt(2023, 9, 22),
+                warranty_end: NaiveDate::from_ymd_opt(2025, 9, 21),
+                applecare: Some(true),
+                replaced: Some(false),
+                refurbished: Some(false),
+                sold_by: None,
+            }
+        );
+        let last_day = Utc.with_ymd_and_hms(2025, 9, 21, 23, 59, 59).unwrap();
+        assert!(warranty.is_in_warranty(last_day));
+        assert_eq!(warranty.days_remaining(last_day), Some(0));
+        assert!(!warranty.is_in_warranty(Utc.with_ymd_and_hms(2025, 9, 22, 0, 0, 0).unwrap()));
+        assert!(!warranty.is_in_warranty(Utc.with_ymd_and_hms(2023, 9, 21, 0, 0, 0).unwrap()));
+
+        let response = service_check_fixture!("apple_sold_by_warranty");
+        let imei = DeviceIdentifier::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap();
+        let warranty = AppleWarranty::from_api_with_sold_by(imei, response.result);
+        assert_eq!(warranty.purchase_date, NaiveDate::from_ymd_opt(2017, 11, 3));
+        assert_eq!(warranty.warranty_end, NaiveDate::from_ymd_opt(2018, 11, 2));
+        assert_eq!(warranty.replaced, Some(true));
+        assert_eq!(warranty.refurbished, None);
+        assert_eq!(
+            warranty.sold_by,
+            Some(SoldBy {
+                seller: Some("AT&T Mobility".to_owned()),
+                purchase_country: Some("United States".to_owned()),
+            })
+        );
+        assert!(!warranty.is_in_warranty(Utc.with_ymd_and_hms(2024, 8, 20, 0, 0, 0).unwrap()));
+
+        // * The schema is not confirmed, so missing fields are unknown rather than failing a paid check
+        let result = serde_json::from_str(r#"{"model": "iPhone X"}"#).unwrap();
+        let warranty = AppleWarranty::from_api_with_sold_by(
+            DeviceIdentifier::from_str(SAMPLE_IMEIS_IPHONE_X[0]).unwrap(),
+            result,
+        );
+        assert_eq!(warranty.warranty_end, None);
+        assert_eq!(
+            warranty.sold_by,
+            Some(SoldBy {
+                seller: None,
+                purchase_country: None,
+            })
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_apple_simlocks() {
+        let response = service_check_fixture!("apple_simlock");
+        let imei = Imei::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap();
+        let simlock = AppleSimlock::from_api(imei, response.result);
+        assert_eq!(simlock.locked, Some(true));
+        assert_eq!(simlock.policy_country.as_deref(), Some("US"));
+        assert_eq!(
+            simlock.next_tether_policy,
+            Some(CarrierPolicy {
+                description: "US T-Mobile/MetroPCS Locked Policy".to_owned(),
+                country_code: Some("US".to_owned()),
+                carrier: Some("T-Mobile".to_owned()),
+                locked: Some(true),
+            })
+        );
+
+        let initial_carrier_policy = simlock.initial_carrier_policy.unwrap();
+        assert_eq!(initial_carrier_policy.carrier.as_deref(), Some("T-Mobile"));
+        assert_eq!(initial_carrier_policy.locked, None);
+        assert_eq!(
+            initial_carrier_policy.operator(),
+            find_operator("310", "260")
+        );
+
+        // * The schema is not confirmed, so a missing lock status falls back to the policy rather than failing
+        let result = serde_json::from_str(
+            r#"{"next_tether_policy": "US AT&T Locked Policy", "initial_carrier_policy": "Unlocked"}"#,
+        )
+        .unwrap();
+        let simlock =
+            AppleSimlock::from_api(Imei::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap(), result);
+        assert_eq!(simlock.locked, Some(true));
+        let simlock = AppleSimlock::from_api(
+            Imei::from_str(SAMPLE_IMEIS_IPHONE_11[0]).unwrap(),
+            serde_json::from_str("{}").unwrap(),
+        );
+        assert_eq!(simlock.locked, None);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn parse_carrier_policies() {
+        let policy = CarrierPolicy::parse("UK Vodafone Locked Policy");
+        assert_eq!(policy.country().unwrap().name, "United Kingdom");
+        assert_eq!(policy.operator(), find_operator("234", "15"));
+
+        let policy = CarrierPolicy::parse("US Verizon Wireless Locked Policy");
+        assert_eq!(policy.carrier.as_deref(), Some("Verizon"));
+
+        let policy = CarrierPolicy::parse("Unlock.");
+        assert_eq!(policy.locked, Some(false));
+        assert_eq!(policy.carrier, None);
+        assert_eq!(policy.country_code, None);
+
+        let policy = CarrierPolicy::parse("US Reseller Flex Policy");
+        assert_eq!(policy.locked, Some(false));
+        assert_eq!(policy.carrier, None);
+
+        // * Carriers which are not in the embedded table keep their name from the description
+        let policy = CarrierPolicy::parse("US Cricket Locked Policy");
+        assert_eq!(policy.carrier.as_deref(), Some("Cricket"));
+        assert_eq!(policy.operator(), None);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_samsung_infos() {
+        use chrono::NaiveDate;
+
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let response = service_check_fixture!("samsung_info");
+        let info = SamsungInfo::from_api(imei.clone(), response.result);
+        assert_eq!(
+            info,
+            SamsungInfo {
+                imei: imei.clone(),
+                model_code: Some("SM-G973F".to_owned()),
+                model_name: Some("Galaxy S10".to_owned()),
+                product_code: Some("SM-G973FZKDXEU".to_owned()),
+                csc: Some("XEU".to_owned()),
+                sold_country: Some("United Kingdom".to_owned()),
+                manufacture_date: NaiveDate::from_ymd_opt(2019, 4, 12),
+                warranty_end: NaiveDate::from_ymd_opt(2021, 4, 11),
+            }
+        );
+
+        let tac_info = |model: &str| PhoneInfo {
+            imeis: imei.clone().into(),
+            manufacturer: "SAMSUNG".to_owned(),
+            model: model.to_owned(),
+        };
+        assert_eq!(
+            info.check_model(&tac_info("Galaxy S10 Exynos")),
+            ModelMatch::Match
+        );
+        assert_eq!(
+            info.check_model(&tac_info("Galaxy S10 (SM-G973U)")),
+            ModelMatch::Match
+        );
+        assert_eq!(
+            info.check_model(&tac_info("Galaxy S10+")),
+            ModelMatch::Mismatch
+        );
+        assert_eq!(
+            info.check_model(&tac_info("Galaxy A52")),
+            ModelMatch::Mismatch
+        );
+        assert_eq!(info.check_model(&tac_info("")), ModelMatch::Unknown);
+
+        let response = service_check_fixture!("samsung_knox_info");
+        assert_eq!(
+            SamsungKnoxInfo::from_api(imei.clone(), response.result),
+            SamsungKnoxInfo {
+                imei,
+                knox_guard: Some(false),
+                knox_enrollment: Some(true),
+                enrolled_organization: Some("Acme Corp".to_owned()),
+                warranty_void: Some(false),
+            }
+        );
+
+        // * The schema is not confirmed, so missing fields are unknown rather than failing a paid check
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let info = SamsungInfo::from_api(
+            imei.clone(),
+            serde_json::from_str(r#"{"csc": "XEU"}"#).unwrap(),
+        );
+        assert_eq!(info.model_code, None);
+        let knox = SamsungKnoxInfo::from_api(imei, serde_json::from_str("{}").unwrap());
+        assert_eq!(knox.knox_guard, None);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_xiaomi_infos() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let imei = Imei::from_str("861953051283743").unwrap();
+        let response = service_check_fixture!("xiaomi_info");
+        let info = XiaomiInfo::from_api(imei.clone(), response.result);
+        assert_eq!(
+            info,
+            XiaomiInfo {
+                imei: imei.clone(),
+                model: Some("Redmi Note 10 Pro".to_owned()),
+                model_code: Some("M2101K6G".to_owned()),
+                region: Some("Global".to_owned()),
+                sold_country: Some("Germany".to_owned()),
+                activated: Some(true),
+                activation_date: NaiveDate::from_ymd_opt(2021, 6, 3),
+                manufacture_date: NaiveDate::from_ymd_opt(2021, 4, 18),
+                warranty_end: NaiveDate::from_ymd_opt(2023, 6, 3),
+            }
+        );
+        assert!(info.is_in_warranty(Utc.with_ymd_and_hms(2023, 6, 3, 23, 0, 0).unwrap()));
+        assert!(!info.is_in_warranty(Utc.with_ymd_and_hms(2023, 6, 4, 0, 0, 0).unwrap()));
+
+        let response = service_check_fixture!("xiaomi_mi_lock_info");
+        let status = XiaomiMiLockStatus::from_api(imei.clone(), response.result);
+        assert_eq!(
+            status,
+            XiaomiMiLockStatus {
+                imei,
+                model: Some("Redmi Note 10 Pro".to_owned()),
+                mi_account_lock: MiLockState::Locked,
+                lost_mode: Some(false),
+            }
+        );
+        assert!(status.is_locked());
+
+        // * The schema is not confirmed, so missing fields are unknown rather than failing a paid check
+        let imei = Imei::from_str("861953051283743").unwrap();
+        let info = XiaomiInfo::from_api(
+            imei.clone(),
+            serde_json::from_str(r#"{"region": "Global"}"#).unwrap(),
+        );
+        assert_eq!(info.model, None);
+        let status = XiaomiMiLockStatus::from_api(imei, serde_json::from_str("{}").unwrap());
+        assert_eq!(status.mi_account_lock, MiLockState::Unknown);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_manufacturer_infos() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let imei = Imei::from_str("351621752948164").unwrap();
+        let response = service_check_fixture!("google_info");
+        let info = ManufacturerInfo::from_api_google(imei.clone(), response.result);
+        assert_eq!(
+            info,
+            ManufacturerInfo {
+                imei,
+                brand: Brand::Google,
+                model: Some("Pixel 7".to_owned()),
+                sku: Some("GVU6C".to_owned()),
+                variant: Some("128GB".to_owned()),
+                region: Some("EU".to_owned()),
+                sold_country: Some("France".to_owned()),
+                manufacture_date: NaiveDate::from_ymd_opt(2022, 9, 14),
+                warranty_end: NaiveDate::from_ymd_opt(2024, 10, 13),
+                details: ManufacturerDetails::Google {
+                    color: Some("Obsidian".to_owned()),
+                    storage: Some("128GB".to_owned()),
+                },
+            }
+        );
+        assert!(info.is_in_warranty(Utc.with_ymd_and_hms(2024, 10, 13, 12, 0, 0).unwrap()));
+
+        let imei = Imei::from_str("354305112739466").unwrap();
+        let response = service_check_fixture!("lg_info");
+        let info = ManufacturerInfo::from_api_lg(imei, response.result);
+        assert_eq!(info.brand, Brand::Lg);
+        assert_eq!(info.sku.as_deref(), Some("LM-G850EMW"));
+        assert_eq!(info.manufacture_date, NaiveDate::from_ymd_opt(2019, 11, 2));
+        assert_eq!(info.warranty_end, None);
+        assert!(!info.is_in_warranty(Utc.with_ymd_and_hms(2019, 12, 1, 0, 0, 0).unwrap()));
+        assert_eq!(
+            info.details,
+            ManufacturerDetails::Lg {
+                buyer_code: Some("AITAWH".to_owned()),
+                carrier: Some("Open Market".to_owned()),
+            }
+        );
+
+        let imei = Imei::from_str("352906115384721").unwrap();
+        let response = service_check_fixture!("sony_info");
+        let info = ManufacturerInfo::from_api_sony(imei, response.result);
+        assert_eq!(info.model.as_deref(), Some("Xperia 1 II"));
+        assert_eq!(info.region.as_deref(), Some("Global"));
+        assert_eq!(
+            info.details,
+            ManufacturerDetails::Sony {
+                color: Some("Black".to_owned()),
+                customization: Some("1322-3498".to_owned()),
+            }
+        );
+
+        // * Brands with their own result types convert into the shared shape
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let response = service_check_fixture!("samsung_info");
+        let info = ManufacturerInfo::from(SamsungInfo::from_api(imei, response.result));
+        assert_eq!(info.brand, Brand::Samsung);
+        assert_eq!(info.sku.as_deref(), Some("SM-G973F"));
+        assert_eq!(info.region.as_deref(), Some("XEU"));
+
+        let imei = Imei::from_str("861953051283743").unwrap();
+        let response = service_check_fixture!("xiaomi_info");
+        let info = ManufacturerInfo::from(XiaomiInfo::from_api(imei, response.result));
+        assert_eq!(info.brand, Brand::Xiaomi);
+        assert_eq!(
+            info.details,
+            ManufacturerDetails::Xiaomi {
+                activated: Some(true),
+                activation_date: NaiveDate::from_ymd_opt(2021, 6, 3),
+            }
+        );
+
+        // * The schema is not confirmed, so a missing model is unknown rather than failing a paid check
+        let imei = Imei::from_str("352906115384721").unwrap();
+        let info = ManufacturerInfo::from_api_sony(
+            imei,
+            serde_json::from_str(r#"{"color": "Black"}"#).unwrap(),
+        );
+        assert_eq!(info.model, None);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_blacklist_statuses() {
+        use chrono::NaiveDate;
+
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let response = service_check_fixture!("blacklist_simple_clean");
+        let status =
+            BlacklistStatus::from_api(imei.clone(), BlacklistTier::Simple, response.result);
+        assert_eq!(status.state, BlacklistState::Clean);
+        assert!(status.reports.is_empty());
+        assert_eq!(status.to_string(), "This device is not blacklisted.");
+
+        let response = service_check_fixture!("blacklist_premium_blacklisted");
+        let status =
+            BlacklistStatus::from_api(imei.clone(), BlacklistTier::Premium, response.result);
+        assert!(status.is_blacklisted());
+        assert_eq!(
+            status.reports[0],
+            BlacklistReport {
+                carrier: Some("Verizon".to_owned()),
+                country: Some("United States".to_owned()),
+                reason: Some(BlacklistReason::Stolen),
+                reported_at: NaiveDate::from_ymd_opt(2024, 1, 5),
+            }
+        );
+        assert_eq!(status.reports[1].reason, Some(BlacklistReason::Unpaid));
+        assert_eq!(
+            status.reports[2].reason,
+            Some(BlacklistReason::Other("Fraud".to_owned()))
+        );
+        assert_eq!(
+            status.to_string(),
+            "This device is blacklisted: reported stolen by Verizon (United States) on 2024-01-05; \
+             reported for an unpaid balance by O2 (United Kingdom) on 2024-02-11; reported (Fraud) in Germany."
+        );
+
+        assert_eq!(
+            BlacklistTier::Premium.service(),
+            Service::BlacklistPremiumCheck
+        );
+        assert_eq!(
+            BlacklistReason::parse("Lost device"),
+            Some(BlacklistReason::Lost)
+        );
+        assert_eq!(BlacklistReason::parse(" "), None);
+
+        // * The schema is not confirmed, so a missing status is unknown rather than failing a paid check
+        let status = BlacklistStatus::from_api(
+            imei.clone(),
+            BlacklistTier::Simple,
+            serde_json::from_str("{}").unwrap(),
+        );
+        assert_eq!(status.state, BlacklistState::Unknown);
+        let status = BlacklistStatus::from_api(
+            imei,
+            BlacklistTier::Simple,
+            serde_json::from_str(r#"{"reason": "Stolen"}"#).unwrap(),
+        );
+        assert!(status.is_blacklisted());
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_carrier_statuses() {
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let response = service_check_fixture!("carrier_lookup");
+        let status =
+            CarrierStatus::from_api(imei.clone().into(), CarrierCheck::Lookup, response.result);
+        assert_eq!(
+            status,
+            CarrierStatus {
+                identifier: CarrierIdentifier::Imei(imei.clone()),
+                check: CarrierCheck::Lookup,
+                original_carrier: find_operator("310", "260").map(Carrier::Operator),
+                locked_carrier: find_operator("310", "260").map(Carrier::Operator),
+                country_code: Some("US".to_owned()),
+                sim_locked: Some(true),
+                financed: None,
+                unpaid_balance: None,
+                blacklisted: Some(false),
+                activation_eligible: None,
+            }
+        );
+        assert_eq!(
+            status
+                .locked_operator()
+                .map(|operator| operator.mobile_network_code),
+            Some("260")
+        );
+        assert!(status.has_reported_issues());
+
+        // * Carriers from the operator table keep their codes through serialization
+        let serialized = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CarrierStatus>(&serialized).unwrap(),
+            status
+        );
+
+        let response = service_check_fixture!("verizon_usa_check");
+        let status = CarrierStatus::from_api(imei.into(), CarrierCheck::Verizon, response.result);
+        assert_eq!(
+            status.original_carrier.as_ref().map(Carrier::name),
+            Some("Verizon")
+        );
+        assert_eq!(status.original_operator(), CarrierCheck::Verizon.operator());
+        assert_eq!(status.locked_carrier, None);
+        assert_eq!(status.sim_locked, Some(false));
+        assert_eq!(status.financed, Some(true));
+        assert_eq!(status.unpaid_balance, Some(false));
+        assert_eq!(status.blacklisted, Some(false));
+        assert_eq!(status.activation_eligible, Some(false));
+        assert!(status.has_reported_issues());
+
+        assert_eq!(CarrierCheck::TMobile.service(), Service::TMobileUsaCheck);
+        assert_eq!(CarrierCheck::Lookup.operator(), None);
+
+        // * The schema is not confirmed, so missing fields are unknown rather than failing a paid check
+        let imei = Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap();
+        let status = CarrierStatus::from_api(
+            imei.into(),
+            CarrierCheck::Verizon,
+            serde_json::from_str("{}").unwrap(),
+        );
+        assert_eq!(status.sim_locked, None);
+        assert!(!status.has_reported_issues());
+
+        // * Phone numbers fall back to the country of their calling code when the lookup does not report one
+        let phone_number = Msisdn::from_str("+4915112345678").unwrap();
+        let status = CarrierStatus::from_api(
+            phone_number.clone().into(),
+            CarrierCheck::Lookup,
+            serde_json::from_str(r#"{"original_carrier": "Vodafone Germany"}"#).unwrap(),
+        );
+        assert_eq!(status.country_code.as_deref(), Some("DE"));
+        assert_eq!(status.original_operator(), find_operator("262", "02"));
+
+        let status = CarrierStatus::from_api(
+            phone_number.into(),
+            CarrierCheck::Lookup,
+            serde_json::from_str(r#"{"original_carrier": "Fonic"}"#).unwrap(),
+        );
+        assert_eq!(
+            status.original_carrier,
+            Some(Carrier::Unlisted("Fonic".to_owned()))
+        );
+        assert_eq!(status.original_operator(), None);
+        assert_eq!(
+            serde_json::to_string(&status.identifier).unwrap(),
+            r#""+4915112345678""#
+        );
+        assert_eq!(
+            CarrierIdentifier::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]),
+            Ok(CarrierIdentifier::Imei(
+                Imei::from_str(SAMPLE_IMEIS_SAMSUNG_S10[0]).unwrap()
+            ))
+        );
+    }
 }
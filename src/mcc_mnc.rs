@@ -0,0 +1,410 @@
+/// A country, identified by its mobile country codes (MCCs) and its E.164 calling code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Country {
+    /// The ISO 3166-1 alpha-2 code of the country, such as `"US"`.
+    pub iso_code: &'static str,
+    pub name: &'static str,
+    /// The E.164 calling code of the country, without the leading `+`.
+    pub calling_code: &'static str,
+    pub mobile_country_codes: &'static [&'static str],
+}
+
+/// A mobile network operator, identified by its mobile country code (MCC) and mobile network code (MNC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Operator {
+    pub mobile_country_code: &'static str,
+    /// The mobile network code, which is two or three digits long depending on the country.
+    pub mobile_network_code: &'static str,
+    pub name: &'static str,
+}
+
+impl Operator {
+    /// Retrieve the country the operator is licensed in, if it is in the embedded country table.
+    pub fn country(&self) -> Option<&'static Country> {
+        find_country_by_mcc(self.mobile_country_code)
+    }
+}
+
+/// Mobile country codes in which MNCs are three digits long unless the embedded operator table says otherwise.
+/// This is mostly North America and the Caribbean, along with a few countries in Latin America.
+const THREE_DIGIT_MNC_COUNTRY_CODES: [&str; 24] = [
+    "302", "310", "311", "312", "313", "314", "315", "316", "330", "334", "338", "342", "344",
+    "346", "348", "352", "354", "356", "358", "360", "365", "376", "722", "732",
+];
+
+/// The embedded country table. It covers the countries with the most mobile subscribers, not every ITU-T assignment.
+pub const COUNTRIES: &[Country] = &[
+    country("AF", "Afghanistan", "93", &["412"]),
+    country("AL", "Albania", "355", &["276"]),
+    country("DZ", "Algeria", "213", &["603"]),
+    country("AD", "Andorra", "376", &["213"]),
+    country("AO", "Angola", "244", &["631"]),
+    country("AR", "Argentina", "54", &["722"]),
+    country("AM", "Armenia", "374", &["283"]),
+    country("AU", "Australia", "61", &["505"]),
+    country("AT", "Austria", "43", &["232"]),
+    country("AZ", "Azerbaijan", "994", &["400"]),
+    country("BS", "Bahamas", "1", &["364"]),
+    country("BH", "Bahrain", "973", &["426"]),
+    country("BD", "Bangladesh", "880", &["470"]),
+    country("BB", "Barbados", "1", &["342"]),
+    country("BY", "Belarus", "375", &["257"]),
+    country("BE", "Belgium", "32", &["206"]),
+    country("BJ", "Benin", "229", &["616"]),
+    country("BM", "Bermuda", "1", &["350"]),
+    country("BT", "Bhutan", "975", &["402"]),
+    country("BO", "Bolivia", "591", &["736"]),
+    country("BA", "Bosnia and Herzegovina", "387", &["218"]),
+    country("BW", "Botswana", "267", &["652"]),
+    country("BR", "Brazil", "55", &["724"]),
+    country("BN", "Brunei", "673", &["528"]),
+    country("BG", "Bulgaria", "359", &["284"]),
+    country("BF", "Burkina Faso", "226", &["613"]),
+    country("KH", "Cambodia", "855", &["456"]),
+    country("CM", "Cameroon", "237", &["624"]),
+    country("CA", "Canada", "1", &["302"]),
+    country("KY", "Cayman Islands", "1", &["346"]),
+    country("CL", "Chile", "56", &["730"]),
+    country("CN", "China", "86", &["460", "461"]),
+    country("CO", "Colombia", "57", &["732"]),
+    country("CR", "Costa Rica", "506", &["712"]),
+    country("CI", "Côte d'Ivoire", "225", &["612"]),
+    country("HR", "Croatia", "385", &["219"]),
+    country("CU", "Cuba", "53", &["368"]),
+    country("CY", "Cyprus", "357", &["280"]),
+    country("CZ", "Czechia", "420", &["230"]),
+    country("CD", "Democratic Republic of the Congo", "243", &["630"]),
+    country("DK", "Denmark", "45", &["238"]),
+    country("DO", "Dominican Republic", "1", &["370"]),
+    country("EC", "Ecuador", "593", &["740"]),
+    country("EG", "Egypt", "20", &["602"]),
+    country("SV", "El Salvador", "503", &["706"]),
+    country("EE", "Estonia", "372", &["248"]),
+    country("ET", "Ethiopia", "251", &["636"]),
+    country("FI", "Finland", "358", &["244"]),
+    country("FR", "France", "33", &["208"]),
+    country("GE", "Georgia", "995", &["282"]),
+    country("DE", "Germany", "49", &["262"]),
+    country("GH", "Ghana", "233", &["620"]),
+    country("GR", "Greece", "30", &["202"]),
+    country("GT", "Guatemala", "502", &["704"]),
+    country("HN", "Honduras", "504", &["708"]),
+    country("HK", "Hong Kong", "852", &["454"]),
+    country("HU", "Hungary", "36", &["216"]),
+    country("IS", "Iceland", "354", &["274"]),
+    country("IN", "India", "91", &["404", "405", "406"]),
+    country("ID", "Indonesia", "62", &["510"]),
+    country("IR", "Iran", "98", &["432"]),
+    country("IQ", "Iraq", "964", &["418"]),
+    country("IE", "Ireland", "353", &["272"]),
+    country("IL", "Israel", "972", &["425"]),
+    country("IT", "Italy", "39", &["222"]),
+    country("JM", "Jamaica", "1", &["338"]),
+    country("JP", "Japan", "81", &["440", "441"]),
+    country("JO", "Jordan", "962", &["416"]),
+    country("KZ", "Kazakhstan", "7", &["401"]),
+    country("KE", "Kenya", "254", &["639"]),
+    country("KW", "Kuwait", "965", &["419"]),
+    country("KG", "Kyrgyzstan", "996", &["437"]),
+    country("LA", "Laos", "856", &["457"]),
+    country("LV", "Latvia", "371", &["247"]),
+    country("LB", "Lebanon", "961", &["415"]),
+    country("LY", "Libya", "218", &["606"]),
+    country("LI", "Liechtenstein", "423", &["295"]),
+    country("LT", "Lithuania", "370", &["246"]),
+    country("LU", "Luxembourg", "352", &["270"]),
+    country("MO", "Macao", "853", &["455"]),
+    country("MG", "Madagascar", "261", &["646"]),
+    country("MY", "Malaysia", "60", &["502"]),
+    country("MV", "Maldives", "960", &["472"]),
+    country("ML", "Mali", "223", &["610"]),
+    country("MT", "Malta", "356", &["278"]),
+    country("MX", "Mexico", "52", &["334"]),
+    country("MD", "Moldova", "373", &["259"]),
+    country("MC", "Monaco", "377", &["212"]),
+    country("MN", "Mongolia", "976", &["428"]),
+    country("ME", "Montenegro", "382", &["297"]),
+    country("MA", "Morocco", "212", &["604"]),
+    country("MZ", "Mozambique", "258", &["643"]),
+    country("MM", "Myanmar", "95", &["414"]),
+    country("NA", "Namibia", "264", &["649"]),
+    country("NP", "Nepal", "977", &["429"]),
+    country("NL", "Netherlands", "31", &["204"]),
+    country("NZ", "New Zealand", "64", &["530"]),
+    country("NI", "Nicaragua", "505", &["710"]),
+    country("NE", "Niger", "227", &["614"]),
+    country("NG", "Nigeria", "234", &["621"]),
+    country("MK", "North Macedonia", "389", &["294"]),
+    country("NO", "Norway", "47", &["242"]),
+    country("OM", "Oman", "968", &["422"]),
+    country("PK", "Pakistan", "92", &["410"]),
+    country("PA", "Panama", "507", &["714"]),
+    country("PY", "Paraguay", "595", &["744"]),
+    country("PE", "Peru", "51", &["716"]),
+    country("PH", "Philippines", "63", &["515"]),
+    country("PL", "Poland", "48", &["260"]),
+    country("PT", "Portugal", "351", &["268"]),
+    country("PR", "Puerto Rico", "1", &["330"]),
+    country("QA", "Qatar", "974", &["427"]),
+    country("RO", "Romania", "40", &["226"]),
+    country("RU", "Russia", "7", &["250"]),
+    country("RW", "Rwanda", "250", &["635"]),
+    country("SA", "Saudi Arabia", "966", &["420"]),
+    country("SN", "Senegal", "221", &["608"]),
+    country("RS", "Serbia", "381", &["220"]),
+    country("SG", "Singapore", "65", &["525"]),
+    country("SK", "Slovakia", "421", &["231"]),
+    country("SI", "Slovenia", "386", &["293"]),
+    country("ZA", "South Africa", "27", &["655"]),
+    country("KR", "South Korea", "82", &["450"]),
+    country("ES", "Spain", "34", &["214"]),
+    country("LK", "Sri Lanka", "94", &["413"]),
+    country("SD", "Sudan", "249", &["634"]),
+    country("SE", "Sweden", "46", &["240"]),
+    country("CH", "Switzerland", "41", &["228"]),
+    country("SY", "Syria", "963", &["417"]),
+    country("TW", "Taiwan", "886", &["466"]),
+    country("TJ", "Tajikistan", "992", &["436"]),
+    country("TZ", "Tanzania", "255", &["640"]),
+    country("TH", "Thailand", "66", &["520"]),
+    country("TT", "Trinidad and Tobago", "1", &["374"]),
+    country("TN", "Tunisia", "216", &["605"]),
+    country("TR", "Türkiye", "90", &["286"]),
+    country("TM", "Turkmenistan", "993", &["438"]),
+    country("UG", "Uganda", "256", &["641"]),
+    country("UA", "Ukraine", "380", &["255"]),
+    country("AE", "United Arab Emirates", "971", &["424", "430", "431"]),
+    country("GB", "United Kingdom", "44", &["234", "235"]),
+    country(
+        "US",
+        "United States",
+        "1",
+        &["310", "311", "312", "313", "314", "315", "316"],
+    ),
+    country("UY", "Uruguay", "598", &["748"]),
+    country("UZ", "Uzbekistan", "998", &["434"]),
+    country("VE", "Venezuela", "58", &["734"]),
+    country("VN", "Vietnam", "84", &["452"]),
+    country("YE", "Yemen", "967", &["421"]),
+    country("ZM", "Zambia", "260", &["645"]),
+    country("ZW", "Zimbabwe", "263", &["648"]),
+];
+
+/// The embedded operator table. It covers the largest operators in the largest markets, along with the
+/// `001-01` test network.
+pub const OPERATORS: &[Operator] = &[
+    operator("001", "01", "Test Network"),
+    operator("204", "04", "Vodafone"),
+    operator("204", "08", "KPN"),
+    operator("204", "16", "Odido"),
+    operator("208", "01", "Orange"),
+    operator("208", "10", "SFR"),
+    operator("208", "15", "Free Mobile"),
+    operator("208", "20", "Bouygues Telecom"),
+    operator("214", "01", "Vodafone"),
+    operator("214", "03", "Orange"),
+    operator("214", "07", "Movistar"),
+    operator("222", "01", "TIM"),
+    operator("222", "10", "Vodafone"),
+    operator("222", "50", "Iliad"),
+    operator("222", "88", "WINDTRE"),
+    operator("222", "99", "WINDTRE"),
+    operator("234", "10", "O2"),
+    operator("234", "15", "Vodafone"),
+    operator("234", "20", "Three"),
+    operator("234", "30", "EE"),
+    operator("234", "33", "EE"),
+    operator("250", "01", "MTS"),
+    operator("250", "02", "MegaFon"),
+    operator("250", "20", "Tele2"),
+    operator("250", "99", "Beeline"),
+    operator("255", "01", "Vodafone"),
+    operator("255", "03", "Kyivstar"),
+    operator("260", "01", "Plus"),
+    operator("260", "02", "T-Mobile"),
+    operator("260", "03", "Orange"),
+    operator("260", "06", "Play"),
+    operator("262", "01", "Telekom"),
+    operator("262", "02", "Vodafone"),
+    operator("262", "03", "O2"),
+    operator("286", "01", "Turkcell"),
+    operator("286", "02", "Vodafone"),
+    operator("286", "03", "Türk Telekom"),
+    operator("302", "220", "Telus"),
+    operator("302", "490", "Freedom Mobile"),
+    operator("302", "610", "Bell"),
+    operator("302", "720", "Rogers"),
+    operator("310", "004", "Verizon"),
+    operator("310", "120", "Sprint"),
+    operator("310", "150", "AT&T"),
+    operator("310", "260", "T-Mobile"),
+    operator("310", "410", "AT&T"),
+    operator("311", "480", "Verizon"),
+    operator("312", "530", "Sprint"),
+    operator("313", "100", "FirstNet"),
+    operator("334", "020", "Telcel"),
+    operator("334", "030", "Movistar"),
+    operator("334", "050", "AT&T"),
+    operator("404", "10", "Airtel"),
+    operator("404", "20", "Vodafone Idea"),
+    operator("404", "45", "Airtel"),
+    operator("405", "857", "Jio"),
+    operator("424", "02", "e&"),
+    operator("424", "03", "du"),
+    operator("440", "10", "NTT docomo"),
+    operator("440", "20", "SoftBank"),
+    operator("440", "50", "au"),
+    operator("450", "05", "SK Telecom"),
+    operator("450", "06", "LG U+"),
+    operator("450", "08", "KT"),
+    operator("460", "00", "China Mobile"),
+    operator("460", "01", "China Unicom"),
+    operator("460", "03", "China Telecom"),
+    operator("460", "11", "China Telecom"),
+    operator("505", "01", "Telstra"),
+    operator("505", "02", "Optus"),
+    operator("505", "03", "Vodafone"),
+    operator("655", "01", "Vodacom"),
+    operator("655", "10", "MTN"),
+    operator("722", "070", "Movistar"),
+    operator("722", "310", "Claro"),
+    operator("722", "340", "Personal"),
+    operator("724", "02", "TIM"),
+    operator("724", "05", "Claro"),
+    operator("724", "06", "Vivo"),
+    operator("732", "101", "Claro"),
+    operator("732", "123", "Movistar"),
+];
+
+const fn country(
+    iso_code: &'static str,
+    name: &'static str,
+    calling_code: &'static str,
+    mobile_country_codes: &'static [&'static str],
+) -> Country {
+    Country {
+        iso_code,
+        name,
+        calling_code,
+        mobile_country_codes,
+    }
+}
+
+const fn operator(
+    mobile_country_code: &'static str,
+    mobile_network_code: &'static str,
+    name: &'static str,
+) -> Operator {
+    Operator {
+        mobile_country_code,
+        mobile_network_code,
+        name,
+    }
+}
+
+/// Find the country a mobile country code (MCC) is assigned to, such as `"310"` for the United States.
+pub fn find_country_by_mcc(mcc: &str) -> Option<&'static Country> {
+    COUNTRIES
+        .iter()
+        .find(|country| country.mobile_country_codes.contains(&mcc))
+}
+
+/// Find a country by its ISO 3166-1 alpha-2 code, ignoring case, such as `"us"` for the United States.
+pub fn find_country_by_iso_code(iso_code: &str) -> Option<&'static Country> {
+    COUNTRIES
+        .iter()
+        .find(|country| country.iso_code.eq_ignore_ascii_case(iso_code))
+}
+
+/// Find the countries which share an E.164 calling code, such as `"44"` for the United Kingdom.
+/// Several countries share the `"1"` (North American Numbering Plan) and `"7"` calling codes.
+pub fn find_countries_by_calling_code(
+    calling_code: &str,
+) -> impl Iterator<Item = &'static Country> + '_ {
+    COUNTRIES
+        .iter()
+        .filter(move |country| country.calling_code == calling_code)
+}
+
+/// Find an operator by its mobile country code (MCC) and mobile network code (MNC), such as `"310"` and `"260"`.
+pub fn find_operator(mcc: &str, mnc: &str) -> Option<&'static Operator> {
+    OPERATORS
+        .iter()
+        .find(|operator| operator.mobile_country_code == mcc && operator.mobile_network_code == mnc)
+}
+
+/// Find an operator by name, ignoring case, whitespace, and punctuation, so that `"T-Mobile USA"`
+/// style variations from different sources can be compared. If a country is given (as an ISO code),
+/// only operators in that country are considered; otherwise the first match in the table is returned.
+pub fn find_operator_by_name(name: &str, iso_code: Option<&str>) -> Option<&'static Operator> {
+    OPERATORS.iter().find(|operator| {
+        names_match(operator.name, name)
+            && iso_code.is_none_or(|iso_code| {
+                operator
+                    .country()
+                    .is_some_and(|country| country.iso_code.eq_ignore_ascii_case(iso_code))
+            })
+    })
+}
+
+/// Get the length of the MNC following the three-digit MCC at the start of the digits of an IMSI.
+/// Operators in the embedded table take precedence, and otherwise the length is based on the country.
+pub(crate) fn mnc_length(digits: &[u8]) -> usize {
+    let (mcc, mnc) = digits.split_at(3);
+    let is_known = |length: usize| {
+        OPERATORS.iter().any(|operator| {
+            digits_match(mcc, operator.mobile_country_code)
+                && digits_match(&mnc[..length], operator.mobile_network_code)
+        })
+    };
+
+    if is_known(3) {
+        3
+    } else if is_known(2) {
+        2
+    } else if THREE_DIGIT_MNC_COUNTRY_CODES
+        .iter()
+        .any(|code| digits_match(mcc, code))
+    {
+        3
+    } else {
+        2
+    }
+}
+
+/// Check whether a sequence of digits is equal to a string of ASCII digits.
+pub(crate) fn digits_match(digits: &[u8], code: &str) -> bool {
+    digits.len() == code.len() && digits.iter().zip(code.bytes()).all(|(d, c)| b'0' + d == c)
+}
+
+/// The number of alphanumeric characters a name from the table needs before it may match as a prefix, so that
+/// short names like `"du"` or `"e&"` only match themselves.
+const MINIMUM_PREFIX_LENGTH: usize = 3;
+
+/// Compare two operator names, ignoring case and any character which is not alphanumeric. The other name may
+/// continue after the name from the table, so that suffixes like `"USA"` or `"Wireless"` are accepted, but only
+/// from a word boundary and only if the name from the table is at least [`MINIMUM_PREFIX_LENGTH`] characters long.
+fn names_match(table_name: &str, name: &str) -> bool {
+    let mut expected = table_name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    let mut matched = 0;
+    let mut chars = name.chars().flat_map(char::to_lowercase);
+    while expected.peek().is_some() {
+        match chars.next() {
+            Some(c) if !c.is_alphanumeric() => {}
+            Some(c) if expected.next() == Some(c) => matched += 1,
+            _ => return false,
+        }
+    }
+
+    match chars.next() {
+        None => true,
+        Some(c) if c.is_alphanumeric() => false,
+        // * Trailing punctuation still counts as an exact match
+        Some(_) if chars.clone().all(|c| !c.is_alphanumeric()) => true,
+        Some(_) => matched >= MINIMUM_PREFIX_LENGTH,
+    }
+}
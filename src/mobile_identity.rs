@@ -0,0 +1,302 @@
+use core::error::Error;
+use core::fmt::Display;
+
+use crate::wrapper::{luhn_checksum, Imei, ImeiSv};
+
+/// The filler nibble used to pad an odd number of BCD digits to a whole number of octets.
+const FILLER: u8 = 0xF;
+/// The odd/even indicator bit in the first octet of a Mobile Identity IE, set when there is an odd number of digits.
+const ODD_INDICATOR: u8 = 0b1000;
+/// The type of identity field in the first octet of a Mobile Identity IE for an IMEI (TS 24.008 table 10.5.4).
+const IMEI_IDENTITY_TYPE: u8 = 0b010;
+/// The type of identity field in the first octet of a Mobile Identity IE for an IMEISV (TS 24.008 table 10.5.4).
+const IMEISV_IDENTITY_TYPE: u8 = 0b011;
+
+/// An IMEI or IMEISV decoded from a Mobile Identity IE by [`decode_mobile_identity`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MobileIdentity {
+    Imei(Imei),
+    ImeiSv(ImeiSv),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MobileIdentityError {
+    IncorrectLength,
+    InvalidDigit,
+    InvalidFiller,
+    UnexpectedIdentityType { identity_type: u8 },
+    OddEvenMismatch,
+    ChecksumDoesNotMatch,
+}
+
+impl Error for MobileIdentityError {}
+
+impl Display for MobileIdentityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MobileIdentityError::IncorrectLength => {
+                f.write_str("the encoded identity does not have the required number of octets")
+            }
+            MobileIdentityError::InvalidDigit => {
+                f.write_str("one or more nibbles of the encoded identity is not a decimal digit")
+            }
+            MobileIdentityError::InvalidFiller => {
+                f.write_str("the final nibble of the encoded identity is not the 0xF filler")
+            }
+            MobileIdentityError::UnexpectedIdentityType { identity_type } => {
+                write!(f, "the type of identity {identity_type} is not an IMEI or IMEISV")
+            }
+            MobileIdentityError::OddEvenMismatch => f.write_str(
+                "the odd/even indicator does not match the number of digits for the type of identity",
+            ),
+            MobileIdentityError::ChecksumDoesNotMatch => {
+                f.write_str("the IMEI check digit does not match its Luhn checksum")
+            }
+        }
+    }
+}
+
+impl Imei {
+    /// Encode the IMEI as TBCD (nibble-swapped BCD, with an `0xF` filler in the final high nibble), the
+    /// layout used by the MAP `IMEI` type and the GTPv2 `MEI` IE.
+    ///
+    /// The check digit is sent as zero, since TS 23.003 requires the final digit of a transmitted IMEI
+    /// to be the spare digit.
+    pub fn to_tbcd(&self) -> [u8; 8] {
+        let mut encoded = [0u8; 8];
+        encode_tbcd(&self.spare_digits(), &mut encoded);
+        encoded
+    }
+
+    /// Decode an IMEI from TBCD, as produced by [`Imei::to_tbcd`].
+    /// If the final (spare) digit is zero, it is replaced with the computed check digit; otherwise it must
+    /// match the Luhn checksum.
+    pub fn from_tbcd(bytes: &[u8]) -> Result<Self, MobileIdentityError> {
+        let bytes: &[u8; 8] = bytes
+            .try_into()
+            .map_err(|_| MobileIdentityError::IncorrectLength)?;
+
+        let mut digits = [0u8; 15];
+        decode_tbcd(bytes, &mut digits)?;
+        Self::from_spare_digits(digits)
+    }
+
+    /// Encode the IMEI as the contents of a TS 24.008 Mobile Identity IE (excluding the IEI and length octets).
+    /// The first octet holds the first digit, the odd/even indicator, and the type of identity, and the
+    /// remaining digits follow in TBCD. As with [`Imei::to_tbcd`], the check digit is sent as zero.
+    pub fn to_mobile_identity(&self) -> [u8; 8] {
+        let mut encoded = [0u8; 8];
+        encode_mobile_identity(&self.spare_digits(), IMEI_IDENTITY_TYPE, &mut encoded);
+        encoded
+    }
+
+    /// Decode an IMEI from the contents of a TS 24.008 Mobile Identity IE (excluding the IEI and length
+    /// octets), as produced by [`Imei::to_mobile_identity`]. The spare digit is handled as in [`Imei::from_tbcd`].
+    pub fn from_mobile_identity(bytes: &[u8]) -> Result<Self, MobileIdentityError> {
+        match decode_mobile_identity(bytes)? {
+            MobileIdentity::Imei(imei) => Ok(imei),
+            MobileIdentity::ImeiSv(_) => Err(MobileIdentityError::UnexpectedIdentityType {
+                identity_type: IMEISV_IDENTITY_TYPE,
+            }),
+        }
+    }
+
+    /// Parse the 14-digit IMEI (without a check digit) carried by the `IMEI` AVP of the Diameter
+    /// `Terminal-Information` AVP, computing the check digit.
+    pub fn from_terminal_information(imei: &str) -> Result<Self, MobileIdentityError> {
+        let bytes: &[u8; 14] = imei
+            .as_bytes()
+            .try_into()
+            .map_err(|_| MobileIdentityError::IncorrectLength)?;
+
+        let mut digits = [0u8; 15];
+        for (digit, c) in digits.iter_mut().zip(bytes) {
+            if !c.is_ascii_digit() {
+                return Err(MobileIdentityError::InvalidDigit);
+            }
+
+            *digit = c - b'0';
+        }
+
+        digits[14] = luhn_checksum(&digits[..14]);
+        Ok(Self { digits })
+    }
+
+    fn spare_digits(&self) -> [u8; 15] {
+        let mut digits = self.digits;
+        digits[14] = 0;
+        digits
+    }
+
+    fn from_spare_digits(mut digits: [u8; 15]) -> Result<Self, MobileIdentityError> {
+        let check_digit = luhn_checksum(&digits[..14]);
+        if digits[14] == 0 {
+            digits[14] = check_digit;
+        } else if digits[14] != check_digit {
+            return Err(MobileIdentityError::ChecksumDoesNotMatch);
+        }
+
+        Ok(Self { digits })
+    }
+}
+
+impl ImeiSv {
+    /// Encode the IMEISV as TBCD (nibble-swapped BCD), the layout used by the MAP `IMEI` type and the GTPv2 `MEI` IE.
+    /// Since the IMEISV has an even number of digits, no filler is needed.
+    pub fn to_tbcd(&self) -> [u8; 8] {
+        let mut encoded = [0u8; 8];
+        encode_tbcd(&self.digits, &mut encoded);
+        encoded
+    }
+
+    /// Decode an IMEISV from TBCD, as produced by [`ImeiSv::to_tbcd`].
+    pub fn from_tbcd(bytes: &[u8]) -> Result<Self, MobileIdentityError> {
+        let bytes: &[u8; 8] = bytes
+            .try_into()
+            .map_err(|_| MobileIdentityError::IncorrectLength)?;
+
+        let mut digits = [0u8; 16];
+        decode_tbcd(bytes, &mut digits)?;
+        Ok(Self { digits })
+    }
+
+    /// Encode the IMEISV as the contents of a TS 24.008 Mobile Identity IE (excluding the IEI and length octets).
+    /// Since the first digit shares an octet with the header, the final octet is padded with the `0xF` filler.
+    pub fn to_mobile_identity(&self) -> [u8; 9] {
+        let mut encoded = [0u8; 9];
+        encode_mobile_identity(&self.digits, IMEISV_IDENTITY_TYPE, &mut encoded);
+        encoded
+    }
+
+    /// Decode an IMEISV from the contents of a TS 24.008 Mobile Identity IE (excluding the IEI and length
+    /// octets), as produced by [`ImeiSv::to_mobile_identity`].
+    pub fn from_mobile_identity(bytes: &[u8]) -> Result<Self, MobileIdentityError> {
+        match decode_mobile_identity(bytes)? {
+            MobileIdentity::ImeiSv(imeisv) => Ok(imeisv),
+            MobileIdentity::Imei(_) => Err(MobileIdentityError::UnexpectedIdentityType {
+                identity_type: IMEI_IDENTITY_TYPE,
+            }),
+        }
+    }
+
+    /// Parse the `IMEI` and `Software-Version` AVPs of the Diameter `Terminal-Information` AVP, which
+    /// carry the 14-digit IMEI without its check digit and the two-digit SVN.
+    pub fn from_terminal_information(
+        imei: &str,
+        software_version: &str,
+    ) -> Result<Self, MobileIdentityError> {
+        let imei = Imei::from_terminal_information(imei)?;
+        let software_version: &[u8; 2] = software_version
+            .as_bytes()
+            .try_into()
+            .map_err(|_| MobileIdentityError::IncorrectLength)?;
+
+        if !software_version.iter().all(u8::is_ascii_digit) {
+            return Err(MobileIdentityError::InvalidDigit);
+        }
+
+        let mut digits = [0u8; 16];
+        digits[..14].copy_from_slice(imei.without_check_digit());
+        digits[14] = software_version[0] - b'0';
+        digits[15] = software_version[1] - b'0';
+        Ok(Self { digits })
+    }
+}
+
+/// Decode the contents of a TS 24.008 Mobile Identity IE (excluding the IEI and length octets) holding
+/// either an IMEI or an IMEISV, based on its type of identity.
+pub fn decode_mobile_identity(bytes: &[u8]) -> Result<MobileIdentity, MobileIdentityError> {
+    let Some(header) = bytes.first() else {
+        return Err(MobileIdentityError::IncorrectLength);
+    };
+
+    let identity_type = header & 0b111;
+    let is_odd = header & ODD_INDICATOR != 0;
+    match identity_type {
+        IMEI_IDENTITY_TYPE => {
+            if !is_odd {
+                return Err(MobileIdentityError::OddEvenMismatch);
+            }
+
+            let mut digits = [0u8; 15];
+            decode_mobile_identity_digits(bytes, 8, &mut digits)?;
+            Ok(MobileIdentity::Imei(Imei::from_spare_digits(digits)?))
+        }
+        IMEISV_IDENTITY_TYPE => {
+            if is_odd {
+                return Err(MobileIdentityError::OddEvenMismatch);
+            }
+
+            let mut digits = [0u8; 16];
+            decode_mobile_identity_digits(bytes, 9, &mut digits)?;
+            Ok(MobileIdentity::ImeiSv(ImeiSv { digits }))
+        }
+        identity_type => Err(MobileIdentityError::UnexpectedIdentityType { identity_type }),
+    }
+}
+
+/// Pack digits into TBCD octets, with the first digit of each pair in the low nibble and an `0xF` filler
+/// in the final high nibble if there is an odd number of digits.
+fn encode_tbcd(digits: &[u8], encoded: &mut [u8]) {
+    for (octet, pair) in encoded.iter_mut().zip(digits.chunks(2)) {
+        let high = pair.get(1).copied().unwrap_or(FILLER);
+        *octet = (high << 4) | pair[0];
+    }
+}
+
+/// Unpack TBCD octets into digits, requiring an `0xF` filler in the final high nibble if there is an odd
+/// number of digits.
+fn decode_tbcd(encoded: &[u8], digits: &mut [u8]) -> Result<(), MobileIdentityError> {
+    let nibbles = encoded.iter().flat_map(|octet| [octet & 0x0F, octet >> 4]);
+
+    decode_nibbles(nibbles, digits)
+}
+
+fn encode_mobile_identity(digits: &[u8], identity_type: u8, encoded: &mut [u8]) {
+    let odd_indicator = if digits.len() % 2 == 1 {
+        ODD_INDICATOR
+    } else {
+        0
+    };
+
+    encoded[0] = (digits[0] << 4) | odd_indicator | identity_type;
+    encode_tbcd(&digits[1..], &mut encoded[1..]);
+}
+
+fn decode_mobile_identity_digits(
+    bytes: &[u8],
+    expected_length: usize,
+    digits: &mut [u8],
+) -> Result<(), MobileIdentityError> {
+    if bytes.len() != expected_length {
+        return Err(MobileIdentityError::IncorrectLength);
+    }
+
+    let nibbles = core::iter::once(bytes[0] >> 4).chain(
+        bytes[1..]
+            .iter()
+            .flat_map(|octet| [octet & 0x0F, octet >> 4]),
+    );
+
+    decode_nibbles(nibbles, digits)
+}
+
+/// Fill the digits from a sequence of nibbles, which must contain exactly enough digits and, if there is
+/// one nibble left over, end with the `0xF` filler.
+fn decode_nibbles(
+    mut nibbles: impl Iterator<Item = u8>,
+    digits: &mut [u8],
+) -> Result<(), MobileIdentityError> {
+    for digit in digits.iter_mut() {
+        match nibbles.next() {
+            Some(nibble) if nibble <= 9 => *digit = nibble,
+            Some(_) => return Err(MobileIdentityError::InvalidDigit),
+            None => return Err(MobileIdentityError::IncorrectLength),
+        }
+    }
+
+    match nibbles.next() {
+        None | Some(FILLER) => Ok(()),
+        Some(_) => Err(MobileIdentityError::InvalidFiller),
+    }
+}
@@ -0,0 +1,122 @@
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::wrapper::{Imei, Tac};
+
+/// The largest difference between the serial numbers of the two IMEIs of a device which is considered plausible.
+/// Manufacturers usually allocate the second IMEI right after the first, so the serial numbers differ by one or
+/// by a small block size.
+pub const MAX_PAIRED_SERIAL_DISTANCE: u32 = 100;
+
+/// The IMEIs of a device. Dual-SIM devices (including ones with an eSIM) have a second IMEI, one for each modem slot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeviceImeis {
+    pub primary: Imei,
+    pub secondary: Option<Imei>,
+}
+
+/// The reason two IMEIs are unlikely to belong to the same device, returned by [`DeviceImeis::check_consistency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImeiPairMismatch {
+    /// The check digit of the primary IMEI does not match its checksum.
+    InvalidPrimary,
+    /// The check digit of the secondary IMEI does not match its checksum.
+    InvalidSecondary,
+    /// Both IMEIs are the same, which a device never reports for two different slots.
+    Identical,
+    /// The TACs are not in the same family and are not a known pair, so the IMEIs belong to different models.
+    DifferentTacFamily,
+    /// The serial numbers are further apart than [`MAX_PAIRED_SERIAL_DISTANCE`].
+    DistantSerialNumbers { distance: u32 },
+}
+
+impl DeviceImeis {
+    pub fn new(primary: Imei, secondary: Option<Imei>) -> Self {
+        Self { primary, secondary }
+    }
+
+    /// Iterate over the IMEIs of the device, starting with the primary one.
+    pub fn iter(&self) -> impl Iterator<Item = &Imei> {
+        core::iter::once(&self.primary).chain(&self.secondary)
+    }
+
+    /// Check whether the two IMEIs plausibly belong to the same device, without making a request. A mismatch
+    /// usually means that the board has been swapped or that one of the IMEIs was typed in wrong.
+    ///
+    /// The TACs are in the same family if they share the first six digits, which is the case for most
+    /// dual-SIM devices with separate TACs for each slot. A device with a single IMEI is always consistent.
+    pub fn check_consistency(&self) -> Result<(), ImeiPairMismatch> {
+        self.check_consistency_with(&[])
+    }
+
+    /// Check whether the two IMEIs plausibly belong to the same device, as with [`DeviceImeis::check_consistency`],
+    /// but also accept the given pairs of TACs, in either order, for devices whose slots use unrelated TACs.
+    pub fn check_consistency_with(
+        &self,
+        paired_tacs: &[(Tac, Tac)],
+    ) -> Result<(), ImeiPairMismatch> {
+        let primary = &self.primary;
+        if !primary.is_valid() {
+            return Err(ImeiPairMismatch::InvalidPrimary);
+        }
+
+        let Some(secondary) = &self.secondary else {
+            return Ok(());
+        };
+
+        if !secondary.is_valid() {
+            return Err(ImeiPairMismatch::InvalidSecondary);
+        }
+
+        if primary == secondary {
+            return Err(ImeiPairMismatch::Identical);
+        }
+
+        let primary_tac = primary.type_allocation_code();
+        let secondary_tac = secondary.type_allocation_code();
+        let is_known_pair = paired_tacs.iter().any(|(a, b)| {
+            (&a.digits == primary_tac && &b.digits == secondary_tac)
+                || (&a.digits == secondary_tac && &b.digits == primary_tac)
+        });
+        if primary_tac[..6] != secondary_tac[..6] && !is_known_pair {
+            return Err(ImeiPairMismatch::DifferentTacFamily);
+        }
+
+        let distance = serial_number_value(primary).abs_diff(serial_number_value(secondary));
+        if distance > MAX_PAIRED_SERIAL_DISTANCE {
+            return Err(ImeiPairMismatch::DistantSerialNumbers { distance });
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Imei> for DeviceImeis {
+    fn from(primary: Imei) -> Self {
+        Self {
+            primary,
+            secondary: None,
+        }
+    }
+}
+
+impl Display for ImeiPairMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ImeiPairMismatch::InvalidPrimary => "the primary IMEI is invalid",
+            ImeiPairMismatch::InvalidSecondary => "the secondary IMEI is invalid",
+            ImeiPairMismatch::Identical => "both IMEIs are the same",
+            ImeiPairMismatch::DifferentTacFamily => "the IMEIs belong to different models",
+            ImeiPairMismatch::DistantSerialNumbers { .. } => {
+                "the serial numbers of the IMEIs are too far apart"
+            }
+        })
+    }
+}
+
+fn serial_number_value(imei: &Imei) -> u32 {
+    imei.serial_number()
+        .iter()
+        .fold(0, |value, d| value * 10 + *d as u32)
+}
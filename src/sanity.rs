@@ -0,0 +1,190 @@
+use core::fmt::Display;
+
+use crate::anatomy::REPORTING_BODIES;
+use crate::wrapper::Imei;
+
+/// IMEIs which are shared by large numbers of devices or copied from documentation, so a lookup never
+/// identifies a single device. This includes the example from the IMEI article on Wikipedia, a widely
+/// used documentation example, the default written by MediaTek firmware when the IMEI is missing, and
+/// IMEIs found on batches of counterfeit handsets.
+const KNOWN_PLACEHOLDER_IMEIS: &[[u8; 15]] = &[
+    [0, 0, 4, 9, 9, 9, 0, 1, 0, 6, 4, 0, 0, 0, 0],
+    [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3, 4, 7],
+    [3, 5, 6, 9, 3, 8, 0, 3, 5, 6, 4, 3, 8, 0, 9],
+    [3, 5, 8, 6, 8, 8, 0, 0, 0, 0, 0, 0, 1, 5, 8],
+    [4, 9, 0, 1, 5, 4, 2, 0, 3, 2, 3, 7, 5, 1, 8],
+];
+
+/// A warning about an IMEI which is valid but unlikely to belong to a single real device, found by [`Imei::sanity_flags`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SanityFlag {
+    /// Every digit is zero, which is what many devices report when the IMEI has been erased.
+    AllZeros,
+    /// The reporting body is `00`, which is reserved for test IMEIs such as the `00101…` IMEIs used by 3GPP test equipment.
+    TestImei,
+    /// The serial number is `000000`, which is typical of IMEIs generated from a TAC rather than read from a device.
+    ZeroSerialNumber,
+    /// The serial number is a single repeated digit, or the whole number repeats a one- or two-digit pattern.
+    RepeatedDigits,
+    /// The serial number counts up or down, such as `123456`.
+    SequentialDigits,
+    /// The IMEI is in the embedded list of placeholder and widely cloned IMEIs.
+    KnownPlaceholder,
+    /// The first two digits of the TAC are not the code of any reporting body, so the TAC cannot have been
+    /// allocated. This does not check whether a TAC with a known reporting body has actually been allocated,
+    /// since that needs a TAC database.
+    UnknownReportingBody,
+}
+
+/// A set of [`SanityFlag`]s, returned by [`Imei::sanity_flags`]. Its `Display` implementation lists the
+/// descriptions of the flags, separated by semicolons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SanityFlags {
+    bits: u8,
+}
+
+impl SanityFlag {
+    const ALL: [SanityFlag; 7] = [
+        SanityFlag::AllZeros,
+        SanityFlag::TestImei,
+        SanityFlag::ZeroSerialNumber,
+        SanityFlag::RepeatedDigits,
+        SanityFlag::SequentialDigits,
+        SanityFlag::KnownPlaceholder,
+        SanityFlag::UnknownReportingBody,
+    ];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl SanityFlags {
+    /// A set containing every flag, for refusing lookups of any flagged IMEI.
+    pub fn all() -> Self {
+        SanityFlag::ALL.into_iter().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn contains(&self, flag: SanityFlag) -> bool {
+        self.bits & flag.bit() != 0
+    }
+
+    pub fn insert(&mut self, flag: SanityFlag) {
+        self.bits |= flag.bit();
+    }
+
+    /// Check whether the two sets have any flags in common.
+    pub fn intersects(&self, other: SanityFlags) -> bool {
+        self.bits & other.bits != 0
+    }
+
+    /// Iterate over the flags in the set.
+    pub fn iter(&self) -> impl Iterator<Item = SanityFlag> + '_ {
+        SanityFlag::ALL
+            .into_iter()
+            .filter(|flag| self.contains(*flag))
+    }
+}
+
+impl FromIterator<SanityFlag> for SanityFlags {
+    fn from_iter<T: IntoIterator<Item = SanityFlag>>(iter: T) -> Self {
+        let mut flags = Self::default();
+        for flag in iter {
+            flags.insert(flag);
+        }
+
+        flags
+    }
+}
+
+impl From<SanityFlag> for SanityFlags {
+    fn from(flag: SanityFlag) -> Self {
+        Self { bits: flag.bit() }
+    }
+}
+
+impl Imei {
+    /// Check the IMEI against offline rules for test, placeholder, and suspicious IMEIs, without making
+    /// a request. An empty set does not mean that the IMEI belongs to a real device, only that nothing
+    /// about it is obviously wrong.
+    pub fn sanity_flags(&self) -> SanityFlags {
+        let mut flags = SanityFlags::default();
+        let digits = &self.digits;
+        if digits.iter().all(|d| *d == 0) {
+            flags.insert(SanityFlag::AllZeros);
+        }
+
+        if self.reporting_body() == &[0, 0] {
+            flags.insert(SanityFlag::TestImei);
+        }
+
+        let serial_number = self.serial_number();
+        if serial_number.iter().all(|d| *d == 0) {
+            flags.insert(SanityFlag::ZeroSerialNumber);
+        } else if serial_number.iter().all(|d| *d == serial_number[0]) {
+            flags.insert(SanityFlag::RepeatedDigits);
+        }
+
+        // * Checking the period over the digits before the check digit catches patterns like `353535…`
+        let payload = self.without_check_digit();
+        if (1..=2).any(|period| payload.iter().zip(&payload[period..]).all(|(a, b)| a == b))
+            && !flags.contains(SanityFlag::AllZeros)
+        {
+            flags.insert(SanityFlag::RepeatedDigits);
+        }
+
+        let steps = serial_number
+            .windows(2)
+            .map(|pair| (pair[1] + 10 - pair[0]) % 10);
+        if steps.clone().all(|step| step == 1) || steps.clone().all(|step| step == 9) {
+            flags.insert(SanityFlag::SequentialDigits);
+        }
+
+        if KNOWN_PLACEHOLDER_IMEIS.contains(digits) {
+            flags.insert(SanityFlag::KnownPlaceholder);
+        }
+
+        if !REPORTING_BODIES
+            .iter()
+            .any(|(code, _)| code == self.reporting_body())
+        {
+            flags.insert(SanityFlag::UnknownReportingBody);
+        }
+
+        flags
+    }
+}
+
+impl Display for SanityFlag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SanityFlag::AllZeros => "every digit of the IMEI is zero",
+            SanityFlag::TestImei => "the IMEI is reserved for test equipment",
+            SanityFlag::ZeroSerialNumber => "the serial number of the IMEI is zero",
+            SanityFlag::RepeatedDigits => "the IMEI repeats the same digits",
+            SanityFlag::SequentialDigits => "the serial number of the IMEI is sequential",
+            SanityFlag::KnownPlaceholder => "the IMEI is a known placeholder or cloned IMEI",
+            SanityFlag::UnknownReportingBody => {
+                "the TAC of the IMEI does not start with the code of any reporting body"
+            }
+        })
+    }
+}
+
+impl Display for SanityFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, flag) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+
+            write!(f, "{flag}")?;
+        }
+
+        Ok(())
+    }
+}
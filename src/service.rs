@@ -0,0 +1,302 @@
+use core::fmt::Display;
+use core::hash::{Hash, Hasher};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A service of the IMEI.info API, which determines what a check looks up and what it costs.
+///
+/// The plain Apple FMI Status service is not listed, since its numeric ID is not confirmed. Find My iPhone is
+/// reported by [`Service::AppleCarrierLockFmiStatus`] instead, or the service can be called through
+/// [`Service::Custom`] once its ID is known.
+///
+/// Services are compared and hashed by their numeric ID, so a [`Service::Custom`] with the ID of a known service
+/// is equal to it.
+#[derive(Debug, Clone, Copy)]
+pub enum Service {
+    BasicImeiCheck,
+    AppleCarrierLockFmiStatus,
+    BlacklistPremiumCheck,
+    SamsungInfoCheck,
+    AppleSoldByWarrantyCoverage,
+    AppleWarrantyCheck,
+    BlacklistSimpleCheck,
+    TMobileUsaCheck,
+    VerizonUsaCheck,
+    CarrierLookup,
+    EsimInfoCheck,
+    GoogleInfoCheck,
+    LgInfoCheck,
+    SamsungKnoxInfoCheck,
+    SonyInfoCheck,
+    XiaomiInfoCheck,
+    XiaomiMiLockInfoCheck,
+    LostDeviceAdd,
+    LostDeviceCheck,
+    AppleSimlockCheck,
+    MacAddressCheck,
+    /// A service which this crate does not know about, by its numeric ID. Use [`Service::from_id`] to construct
+    /// services from IDs, which returns the known service instead if there is one; a custom service with the ID
+    /// of a known service is still equal to it and describes itself as it.
+    Custom(u32),
+}
+
+/// A kind of identifier which a [`Service`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierKind {
+    Imei,
+    /// A manufacturer serial number, such as an [`AppleSerial`](crate::AppleSerial).
+    SerialNumber,
+    MacAddress,
+    PhoneNumber,
+    /// An ICCID or EID, as an [`EsimIdentifier`](crate::EsimIdentifier).
+    EsimIdentifier,
+}
+
+/// A device manufacturer which some services are limited to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Brand {
+    Apple,
+    Samsung,
+    Google,
+    Lg,
+    Sony,
+    Xiaomi,
+}
+
+/// A result type which is returned by a particular service, so that it can be checked with
+/// [`check_service`](crate::check_service) without naming the service separately.
+/// This can be implemented for custom result types to call services the crate does not model yet.
+pub trait ServiceCheck: DeserializeOwned {
+    const SERVICE: Service;
+}
+
+impl Service {
+    /// Every service known to this crate, in order of numeric ID.
+    pub const KNOWN: [Service; 21] = [
+        Service::BasicImeiCheck,
+        Service::AppleCarrierLockFmiStatus,
+        Service::BlacklistPremiumCheck,
+        Service::SamsungInfoCheck,
+        Service::AppleSoldByWarrantyCoverage,
+        Service::AppleWarrantyCheck,
+        Service::BlacklistSimpleCheck,
+        Service::TMobileUsaCheck,
+        Service::VerizonUsaCheck,
+        Service::CarrierLookup,
+        Service::EsimInfoCheck,
+        Service::GoogleInfoCheck,
+        Service::LgInfoCheck,
+        Service::SamsungKnoxInfoCheck,
+        Service::SonyInfoCheck,
+        Service::XiaomiInfoCheck,
+        Service::XiaomiMiLockInfoCheck,
+        Service::LostDeviceAdd,
+        Service::LostDeviceCheck,
+        Service::AppleSimlockCheck,
+        Service::MacAddressCheck,
+    ];
+
+    /// Look up a service by its numeric ID, falling back to [`Service::Custom`] for unknown IDs.
+    pub fn from_id(id: u32) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|service| service.id() == id)
+            .unwrap_or(Service::Custom(id))
+    }
+
+    /// Retrieve the numeric ID (SID) of the service, which is part of the URL of a check.
+    pub fn id(&self) -> u32 {
+        match self {
+            Service::BasicImeiCheck => 0,
+            Service::AppleCarrierLockFmiStatus => 2,
+            Service::BlacklistPremiumCheck => 3,
+            Service::SamsungInfoCheck => 4,
+            Service::AppleSoldByWarrantyCoverage => 11,
+            Service::AppleWarrantyCheck => 12,
+            Service::BlacklistSimpleCheck => 27,
+            Service::TMobileUsaCheck => 31,
+            Service::VerizonUsaCheck => 32,
+            Service::CarrierLookup => 48,
+            Service::EsimInfoCheck => 52,
+            Service::GoogleInfoCheck => 54,
+            Service::LgInfoCheck => 66,
+            Service::SamsungKnoxInfoCheck => 76,
+            Service::SonyInfoCheck => 80,
+            Service::XiaomiInfoCheck => 84,
+            Service::XiaomiMiLockInfoCheck => 86,
+            Service::LostDeviceAdd => 100,
+            Service::LostDeviceCheck => 101,
+            Service::AppleSimlockCheck => 104,
+            Service::MacAddressCheck => 106,
+            Service::Custom(id) => *id,
+        }
+    }
+
+    /// Retrieve the human-readable name of the service.
+    pub fn name(&self) -> &'static str {
+        match self.canonical() {
+            Service::BasicImeiCheck => "Basic IMEI Check",
+            Service::AppleCarrierLockFmiStatus => "Apple Carrier Lock & FMI Status",
+            Service::BlacklistPremiumCheck => "Blacklist Premium Check",
+            Service::SamsungInfoCheck => "Samsung Info Check",
+            Service::AppleSoldByWarrantyCoverage => "Apple Sold By & Warranty Coverage",
+            Service::AppleWarrantyCheck => "Apple Warranty Check",
+            Service::BlacklistSimpleCheck => "Blacklist Simple Check",
+            Service::TMobileUsaCheck => "T-Mobile USA Check",
+            Service::VerizonUsaCheck => "Verizon USA Check",
+            Service::CarrierLookup => "Carrier Lookup",
+            Service::EsimInfoCheck => "eSIM Info Check",
+            Service::GoogleInfoCheck => "Google Info Check",
+            Service::LgInfoCheck => "LG Info Check",
+            Service::SamsungKnoxInfoCheck => "Samsung Knox Info Check",
+            Service::SonyInfoCheck => "Sony Info Check",
+            Service::XiaomiInfoCheck => "Xiaomi Info Check",
+            Service::XiaomiMiLockInfoCheck => "Xiaomi Mi Lock Info Check",
+            Service::LostDeviceAdd => "Lost Device Add",
+            Service::LostDeviceCheck => "Lost Device Check",
+            Service::AppleSimlockCheck => "Apple SIM Lock Check",
+            Service::MacAddressCheck => "MAC Address Check",
+            Service::Custom(_) => "Custom Service",
+        }
+    }
+
+    /// List the kinds of identifiers the service accepts. Custom services are assumed to accept any kind,
+    /// leaving it up to the API to reject the identifier.
+    pub fn accepted_identifiers(&self) -> &'static [IdentifierKind] {
+        use IdentifierKind::*;
+        match self.canonical() {
+            Service::AppleCarrierLockFmiStatus
+            | Service::AppleSoldByWarrantyCoverage
+            | Service::AppleWarrantyCheck => &[Imei, SerialNumber],
+            Service::SamsungInfoCheck | Service::SamsungKnoxInfoCheck => &[Imei, SerialNumber],
+            Service::EsimInfoCheck => &[EsimIdentifier],
+            Service::MacAddressCheck => &[MacAddress],
+            Service::CarrierLookup => &[Imei, PhoneNumber],
+            Service::Custom(_) => &[Imei, SerialNumber, MacAddress, PhoneNumber, EsimIdentifier],
+            _ => &[Imei],
+        }
+    }
+
+    /// Check whether the service accepts the kind of identifier.
+    pub fn accepts(&self, kind: IdentifierKind) -> bool {
+        self.accepted_identifiers().contains(&kind)
+    }
+
+    /// Retrieve the brand the service is limited to, or `None` if it works for devices of any brand.
+    pub fn brand(&self) -> Option<Brand> {
+        match self.canonical() {
+            Service::AppleCarrierLockFmiStatus
+            | Service::AppleSoldByWarrantyCoverage
+            | Service::AppleWarrantyCheck
+            | Service::AppleSimlockCheck => Some(Brand::Apple),
+            Service::SamsungInfoCheck | Service::SamsungKnoxInfoCheck => Some(Brand::Samsung),
+            Service::GoogleInfoCheck => Some(Brand::Google),
+            Service::LgInfoCheck => Some(Brand::Lg),
+            Service::SonyInfoCheck => Some(Brand::Sony),
+            Service::XiaomiInfoCheck | Service::XiaomiMiLockInfoCheck => Some(Brand::Xiaomi),
+            _ => None,
+        }
+    }
+
+    /// Check whether the service is free to use. Every other service costs tokens, and custom services
+    /// are assumed to cost tokens.
+    pub fn is_free(&self) -> bool {
+        matches!(self.canonical(), Service::BasicImeiCheck)
+    }
+
+    /// Replace a custom service which has the ID of a known service with the known service.
+    fn canonical(&self) -> Self {
+        match self {
+            Service::Custom(id) => Self::from_id(*id),
+            service => *service,
+        }
+    }
+}
+
+impl Brand {
+    /// Match a manufacturer name as returned by the API (such as `"APPLE"` or `"Samsung Electronics"`) to a brand.
+    pub fn from_manufacturer(manufacturer: &str) -> Option<Self> {
+        let manufacturer = manufacturer.trim();
+        [
+            ("apple", Brand::Apple),
+            ("samsung", Brand::Samsung),
+            ("google", Brand::Google),
+            ("lg", Brand::Lg),
+            ("sony", Brand::Sony),
+            ("xiaomi", Brand::Xiaomi),
+        ]
+        .into_iter()
+        .find(|(prefix, _)| {
+            manufacturer
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+                && manufacturer[prefix.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| !c.is_ascii_alphanumeric())
+        })
+        .map(|(_, brand)| brand)
+    }
+
+    /// Retrieve the service which returns the manufacturer's information about a device (model, region, and
+    /// warranty). Apple devices have no such service; use the warranty check instead.
+    pub fn info_service(&self) -> Option<Service> {
+        match self {
+            Brand::Apple => None,
+            Brand::Samsung => Some(Service::SamsungInfoCheck),
+            Brand::Google => Some(Service::GoogleInfoCheck),
+            Brand::Lg => Some(Service::LgInfoCheck),
+            Brand::Sony => Some(Service::SonyInfoCheck),
+            Brand::Xiaomi => Some(Service::XiaomiInfoCheck),
+        }
+    }
+}
+
+impl From<u32> for Service {
+    fn from(id: u32) -> Self {
+        Self::from_id(id)
+    }
+}
+
+impl From<Service> for u32 {
+    fn from(service: Service) -> Self {
+        service.id()
+    }
+}
+
+impl PartialEq for Service {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Service {}
+
+impl Hash for Service {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+impl Display for Service {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.canonical() {
+            Service::Custom(id) => write!(f, "Custom Service ({id})"),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
+impl Display for Brand {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Brand::Apple => "Apple",
+            Brand::Samsung => "Samsung",
+            Brand::Google => "Google",
+            Brand::Lg => "LG",
+            Brand::Sony => "Sony",
+            Brand::Xiaomi => "Xiaomi",
+        })
+    }
+}
@@ -0,0 +1,234 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::sim::FromStrVisitor;
+use crate::wrapper::ImeiWrapperError;
+
+/// Characters encoding the year and half of manufacture in a 12-character serial, starting with the first half of 2010.
+const YEAR_HALF_CHARACTERS: &[u8; 20] = b"CDFGHJKLMNPQRSTVWXYZ";
+/// Characters encoding the week within the half of manufacture in a 12-character serial, starting with week 1.
+const WEEK_CHARACTERS: &[u8; 27] = b"123456789CDFGHJKLMNPQRTVWXY";
+
+/// Known factory prefixes of the manufacturing location, checked from the longest to the shortest.
+const FACTORIES: &[(&str, &str)] = &[
+    ("C02", "Quanta Computer, Shanghai, China"),
+    ("C07", "Quanta Computer, Shanghai, China"),
+    ("C17", "Quanta Computer, Shanghai, China"),
+    ("C1M", "Foxconn, Shenzhen, China"),
+    ("C3", "Foxconn, Shenzhen, China"),
+    ("C7", "Pegatron, Shanghai, China"),
+    ("CK", "Cork, Ireland"),
+    ("CY", "Korea"),
+    ("DL", "Foxconn, China"),
+    ("DM", "Foxconn, China"),
+    ("DN", "Foxconn, Chengdu, China"),
+    ("DX", "Foxconn, China"),
+    ("F1", "Foxconn, Zhengzhou, China"),
+    ("F2", "Foxconn, Zhengzhou, China"),
+    ("F4", "Foxconn, Zhengzhou, China"),
+    ("F5", "Foxconn, Zhengzhou, China"),
+    ("F7", "Foxconn, China"),
+    ("FC", "Fountain, Colorado, USA"),
+    ("FK", "Foxconn, Zhengzhou, China"),
+    ("G8", "USA"),
+    ("GQ", "Foxconn, China"),
+    ("MB", "Malaysia"),
+    ("PT", "Korea"),
+    ("QP", "USA"),
+    ("RM", "Refurbished or remanufactured"),
+    ("RN", "Mexico"),
+    ("SG", "Singapore"),
+    ("UV", "Taiwan"),
+    ("VM", "Foxconn, Pardubice, Czech Republic"),
+    ("W8", "Shanghai, China"),
+    ("XA", "USA"),
+    ("XB", "USA"),
+    ("YM", "Foxconn, China"),
+    ("E", "Singapore"),
+    ("F", "Fremont, California, USA"),
+];
+
+/// An Apple serial number, stored in uppercase.
+///
+/// Serials from before 2010 have 11 characters, serials from 2010 until 2021 have 12 characters, and
+/// both of these encode the manufacturing location, date, and model. Newer serials are 10 random characters.
+/// It is serialized as a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AppleSerial {
+    characters: [u8; 12],
+    length: usize,
+}
+
+/// The format of an [`AppleSerial`], which determines what can be decoded from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AppleSerialFormat {
+    /// `LLYWWSSSCCC`: location, last digit of the year, week, unit ID, and model code.
+    Legacy11,
+    /// `PPPYWSSSCCCC`: location, year and half, week within the half, unit ID, and model code.
+    Legacy12,
+    /// Ten random characters, used since 2021.
+    Randomized,
+}
+
+/// The week a device with a legacy [`AppleSerial`] was manufactured in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ManufacturingWeek {
+    pub year: u16,
+    /// The half of the year, which is 1 or 2.
+    pub half: u8,
+    /// The week of the year, starting at 1.
+    pub week: u8,
+}
+
+impl AppleSerial {
+    /// Retrieve the serial as a string.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.characters[..self.length]).unwrap()
+    }
+
+    /// Retrieve the format of the serial.
+    pub fn format(&self) -> AppleSerialFormat {
+        match self.length {
+            11 => AppleSerialFormat::Legacy11,
+            12 => AppleSerialFormat::Legacy12,
+            _ => AppleSerialFormat::Randomized,
+        }
+    }
+
+    /// Retrieve the manufacturing location code (the first two characters of an 11-character serial, or the first
+    /// three of a 12-character serial). Returns `None` for randomized serials.
+    pub fn manufacturing_location(&self) -> Option<&str> {
+        match self.format() {
+            AppleSerialFormat::Legacy11 => Some(&self.as_str()[0..2]),
+            AppleSerialFormat::Legacy12 => Some(&self.as_str()[0..3]),
+            AppleSerialFormat::Randomized => None,
+        }
+    }
+
+    /// Look up the factory for the manufacturing location code in the embedded table, such as `"Foxconn, Zhengzhou, China"`.
+    pub fn factory(&self) -> Option<&'static str> {
+        let location = self.manufacturing_location()?;
+        FACTORIES
+            .iter()
+            .find(|(prefix, _)| location.starts_with(prefix))
+            .map(|(_, factory)| *factory)
+    }
+
+    /// Decode the week of manufacture. Returns `None` for randomized serials.
+    ///
+    /// 11-character serials only encode the last digit of the year, which is assumed to be between 2003 and 2012.
+    /// 12-character serials encode the year as a letter which repeats every ten years, which is assumed to be
+    /// between 2010 and 2019; devices made in 2020 are reported as made in 2010.
+    pub fn manufacturing_week(&self) -> Option<ManufacturingWeek> {
+        let characters = &self.characters;
+        match self.format() {
+            AppleSerialFormat::Legacy11 => {
+                let year_digit = (characters[2] - b'0') as u16;
+                let year = if year_digit >= 3 { 2000 } else { 2010 } + year_digit;
+                let week = (characters[3] - b'0') * 10 + (characters[4] - b'0');
+                Some(ManufacturingWeek {
+                    year,
+                    half: if week <= 26 { 1 } else { 2 },
+                    week,
+                })
+            }
+            AppleSerialFormat::Legacy12 => {
+                let year_half = position(YEAR_HALF_CHARACTERS, characters[3])?;
+                let week_in_half = position(WEEK_CHARACTERS, characters[4])? + 1;
+                let half = year_half % 2 + 1;
+                Some(ManufacturingWeek {
+                    year: 2010 + year_half as u16 / 2,
+                    half,
+                    week: week_in_half + if half == 2 { 26 } else { 0 },
+                })
+            }
+            AppleSerialFormat::Randomized => None,
+        }
+    }
+
+    /// Retrieve the unit ID, which distinguishes devices of the same model made in the same week (characters 6
+    /// through 8 of a legacy serial). Returns `None` for randomized serials.
+    pub fn unit_id(&self) -> Option<&str> {
+        match self.format() {
+            AppleSerialFormat::Legacy11 | AppleSerialFormat::Legacy12 => Some(&self.as_str()[5..8]),
+            AppleSerialFormat::Randomized => None,
+        }
+    }
+
+    /// Retrieve the model code, which identifies the model and configuration (the last three characters of an
+    /// 11-character serial, or the last four of a 12-character serial). Returns `None` for randomized serials.
+    pub fn model_code(&self) -> Option<&str> {
+        match self.format() {
+            AppleSerialFormat::Legacy11 | AppleSerialFormat::Legacy12 => Some(&self.as_str()[8..]),
+            AppleSerialFormat::Randomized => None,
+        }
+    }
+}
+
+impl FromStr for AppleSerial {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let length = s.len();
+        if !matches!(length, 10..=12) {
+            return Err(ImeiWrapperError::IncorrectLength);
+        }
+
+        let mut characters = [0u8; 12];
+        for (character, c) in characters.iter_mut().zip(s.bytes()) {
+            if !c.is_ascii_alphanumeric() {
+                return Err(ImeiWrapperError::CannotParseDigits);
+            }
+
+            *character = c.to_ascii_uppercase();
+        }
+
+        let is_valid = match length {
+            11 => {
+                characters[2..5].iter().all(u8::is_ascii_digit)
+                    && matches!((characters[3] - b'0') * 10 + (characters[4] - b'0'), 1..=53)
+            }
+            12 => {
+                YEAR_HALF_CHARACTERS.contains(&characters[3])
+                    && WEEK_CHARACTERS.contains(&characters[4])
+            }
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(ImeiWrapperError::CannotParseDigits);
+        }
+
+        Ok(Self { characters, length })
+    }
+}
+
+impl TryFrom<&str> for AppleSerial {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl Display for AppleSerial {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for AppleSerial {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AppleSerial {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an Apple serial number"))
+    }
+}
+
+fn position(characters: &[u8], c: u8) -> Option<u8> {
+    characters.iter().position(|x| *x == c).map(|i| i as u8)
+}
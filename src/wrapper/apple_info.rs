@@ -0,0 +1,264 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    parse_api_date, ApiAppleCarrierLockFmiStatus, ApiAppleSimlock, ApiAppleSoldByWarranty,
+    ApiAppleWarranty, ApiFlag,
+};
+use crate::wrapper::{DeviceIdentifier, Imei};
+use crate::{find_country_by_iso_code, find_operator_by_name, Country, Operator};
+
+/// Words which end a carrier policy name without being part of the carrier name, such as in `"US AT&T Locked Policy"`.
+const POLICY_SUFFIXES: &[&str] = &["policy", "locked", "lock", "activation"];
+/// Policy names which do not lock the device to a carrier.
+const UNLOCKED_POLICIES: &[&str] = &["unlock", "unlocked", "open market", "reseller flex"];
+
+/// Whether Find My iPhone is enabled on a device. While it is on, the device is protected by Activation Lock
+/// and cannot be set up by anyone else without the owner's Apple ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FmiState {
+    On,
+    Off,
+    /// The service did not report the status, or reported it in a way which is not recognized.
+    Unknown,
+}
+
+/// The Find My iPhone and carrier lock status of an Apple device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FmiStatus {
+    pub identifier: DeviceIdentifier,
+    pub model: Option<String>,
+    pub find_my_iphone: FmiState,
+    /// Whether the owner has put the device in Lost Mode, if the service reported it.
+    pub lost_mode: Option<bool>,
+    pub carrier_lock: CarrierLock,
+}
+
+/// Whether an Apple device is locked to a carrier, and to which one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierLock {
+    pub locked: Option<bool>,
+    pub carrier: Option<String>,
+    /// The carrier policy applied to the device, such as `"US AT&T Locked Policy"`.
+    pub policy: Option<String>,
+}
+
+/// The warranty coverage of an Apple device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppleWarranty {
+    pub identifier: DeviceIdentifier,
+    pub model: Option<String>,
+    /// The date the device was first purchased, which Apple sometimes only estimates.
+    pub purchase_date: Option<NaiveDate>,
+    pub warranty_start: Option<NaiveDate>,
+    /// The last day of coverage, whether from the limited warranty or AppleCare.
+    pub warranty_end: Option<NaiveDate>,
+    pub applecare: Option<bool>,
+    /// Whether the device is a replacement for another device, such as one issued by a repair.
+    pub replaced: Option<bool>,
+    pub refurbished: Option<bool>,
+    /// Who sold the device and where, which is only returned by [`get_apple_sold_by_warranty`](crate::get_apple_sold_by_warranty).
+    pub sold_by: Option<SoldBy>,
+}
+
+/// The seller of an Apple device.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SoldBy {
+    /// The company which sold the device, such as a carrier or a retailer.
+    pub seller: Option<String>,
+    pub purchase_country: Option<String>,
+}
+
+/// The SIM lock of an Apple device, as reported by the SIM lock check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppleSimlock {
+    pub imei: Imei,
+    pub model: Option<String>,
+    pub locked: Option<bool>,
+    /// The policy which will be applied the next time the device is activated, which determines whether it is locked.
+    pub next_tether_policy: Option<CarrierPolicy>,
+    /// The policy which was applied when the device was first activated, which usually identifies the original carrier.
+    pub initial_carrier_policy: Option<CarrierPolicy>,
+    /// The ISO code of the country of the next tether policy.
+    pub policy_country: Option<String>,
+}
+
+/// A carrier policy of an Apple device, parsed from a description such as `"US AT&T Locked Policy"`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierPolicy {
+    /// The description of the policy, as reported by the API.
+    pub description: String,
+    /// The ISO code of the country at the start of the description, such as `"US"`. Apple's `"UK"` is reported as `"GB"`.
+    pub country_code: Option<String>,
+    /// The normalized name of the carrier, which uses the name from the embedded operator table when the carrier
+    /// is in it, so that results from different descriptions of the same carrier can be grouped.
+    pub carrier: Option<String>,
+    /// Whether the policy locks the device to the carrier, if it can be told from the description.
+    pub locked: Option<bool>,
+}
+
+impl FmiStatus {
+    pub(crate) fn from_api(
+        identifier: DeviceIdentifier,
+        status: ApiAppleCarrierLockFmiStatus,
+    ) -> Self {
+        Self {
+            identifier,
+            model: status.model,
+            find_my_iphone: match status.fmi_on.as_ref().and_then(ApiFlag::value) {
+                Some(true) => FmiState::On,
+                Some(false) => FmiState::Off,
+                None => FmiState::Unknown,
+            },
+            lost_mode: status.lost_mode.as_ref().and_then(ApiFlag::value),
+            carrier_lock: CarrierLock {
+                locked: status.sim_locked.as_ref().and_then(ApiFlag::value),
+                carrier: status.carrier,
+                policy: status.carrier_policy,
+            },
+        }
+    }
+
+    /// Check whether the device is protected by Activation Lock. Devices with an unknown status are not
+    /// considered locked, so check [`FmiStatus::find_my_iphone`] directly to treat them as locked.
+    pub fn is_activation_locked(&self) -> bool {
+        self.find_my_iphone == FmiState::On
+    }
+}
+
+impl AppleWarranty {
+    pub(crate) fn from_api(identifier: DeviceIdentifier, warranty: ApiAppleWarranty) -> Self {
+        Self {
+            identifier,
+            model: warranty.model,
+            purchase_date: parse_api_date(warranty.purchase_date.as_deref()),
+            warranty_start: parse_api_date(warranty.warranty_start_date.as_deref()),
+            warranty_end: parse_api_date(warranty.warranty_end_date.as_deref()),
+            applecare: warranty.applecare.as_ref().and_then(ApiFlag::value),
+            replaced: warranty.replaced.as_ref().and_then(ApiFlag::value),
+            refurbished: warranty.refurbished.as_ref().and_then(ApiFlag::value),
+            sold_by: None,
+        }
+    }
+
+    pub(crate) fn from_api_with_sold_by(
+        identifier: DeviceIdentifier,
+        warranty: ApiAppleSoldByWarranty,
+    ) -> Self {
+        Self {
+            sold_by: Some(SoldBy {
+                seller: warranty.sold_by,
+                purchase_country: warranty.purchase_country,
+            }),
+            ..Self::from_api(identifier, warranty.warranty)
+        }
+    }
+
+    /// Check whether the device is covered at the given time, including the last day of coverage. Devices
+    /// without a known end of coverage are not considered covered.
+    pub fn is_in_warranty(&self, at: DateTime<Utc>) -> bool {
+        let date = at.date_naive();
+        self.warranty_end.is_some_and(|end| date <= end)
+            && self.warranty_start.is_none_or(|start| start <= date)
+    }
+
+    /// Count the days of coverage left after the given time, which is negative once coverage has ended.
+    pub fn days_remaining(&self, at: DateTime<Utc>) -> Option<i64> {
+        Some((self.warranty_end? - at.date_naive()).num_days())
+    }
+}
+
+impl AppleSimlock {
+    pub(crate) fn from_api(imei: Imei, simlock: ApiAppleSimlock) -> Self {
+        let next_tether_policy = simlock
+            .next_tether_policy
+            .as_deref()
+            .map(CarrierPolicy::parse);
+        let initial_carrier_policy = simlock
+            .initial_carrier_policy
+            .as_deref()
+            .map(CarrierPolicy::parse);
+        Self {
+            imei,
+            model: simlock.model,
+            locked: simlock
+                .simlock
+                .as_ref()
+                .and_then(ApiFlag::value)
+                .or_else(|| next_tether_policy.as_ref()?.locked),
+            policy_country: simlock.policy_country.or_else(|| {
+                next_tether_policy
+                    .as_ref()
+                    .and_then(|policy| policy.country_code.clone())
+            }),
+            next_tether_policy,
+            initial_carrier_policy,
+        }
+    }
+}
+
+impl CarrierPolicy {
+    /// Parse a carrier policy description. The country and carrier are `None` if the description does not
+    /// name them, such as for `"Unlock"` or `"Open Market"`.
+    pub fn parse(description: &str) -> Self {
+        let trimmed = description.trim().trim_end_matches('.');
+        let lowercase = trimmed.to_lowercase();
+        let locked = if UNLOCKED_POLICIES
+            .iter()
+            .any(|policy| lowercase.contains(policy))
+        {
+            Some(false)
+        } else if lowercase.contains("locked") {
+            Some(true)
+        } else {
+            None
+        };
+
+        let (country_code, rest) = match trimmed.split_once(' ') {
+            Some((code, rest))
+                if code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()) =>
+            {
+                let code = if code == "UK" { "GB" } else { code };
+                match find_country_by_iso_code(code) {
+                    Some(country) => (Some(country.iso_code.to_owned()), rest),
+                    None => (None, trimmed),
+                }
+            }
+            _ => (None, trimmed),
+        };
+
+        let mut words: Vec<_> = rest.split_whitespace().collect();
+        while words
+            .last()
+            .is_some_and(|word| POLICY_SUFFIXES.contains(&word.to_lowercase().as_str()))
+        {
+            words.pop();
+        }
+
+        let name = words.join(" ");
+        let carrier = (!name.is_empty()
+            && !UNLOCKED_POLICIES.contains(&name.to_lowercase().as_str()))
+        .then(|| {
+            find_operator_by_name(&name, country_code.as_deref())
+                .map(|operator| operator.name.to_owned())
+                .unwrap_or(name)
+        });
+
+        Self {
+            description: description.to_owned(),
+            country_code,
+            carrier,
+            locked,
+        }
+    }
+
+    /// Look up the country of the policy in the embedded country table.
+    pub fn country(&self) -> Option<&'static Country> {
+        find_country_by_iso_code(self.country_code.as_deref()?)
+    }
+
+    /// Look up the carrier of the policy in the embedded operator table.
+    pub fn operator(&self) -> Option<&'static Operator> {
+        find_operator_by_name(self.carrier.as_deref()?, self.country_code.as_deref())
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Display;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{parse_api_date, ApiBlacklistRecord, ApiBlacklistStatus};
+use crate::wrapper::Imei;
+use crate::Service;
+
+/// Words which services use for a blacklisted device, in addition to the usual yes/no values.
+pub(crate) const BLACKLISTED_WORDS: &[&str] = &["blacklisted", "blocked", "barred"];
+/// Words which services use for a device which is not blacklisted, in addition to the usual yes/no values.
+pub(crate) const CLEAN_WORDS: &[&str] = &["clean"];
+
+/// Which of the blacklist checks to use. The premium check costs more, but covers more carriers and countries
+/// and reports every record rather than just the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlacklistTier {
+    Simple,
+    Premium,
+}
+
+/// Whether a device is on the blacklist of any carrier, which stops it from connecting to their networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlacklistState {
+    Clean,
+    Blacklisted,
+    /// The service did not report the status, or reported it in a way which is not recognized.
+    Unknown,
+}
+
+/// Why a device was blacklisted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlacklistReason {
+    Lost,
+    Stolen,
+    /// The account the device was bought on has an unpaid balance, such as from unpaid bills or financing.
+    Unpaid,
+    /// A reason which is not recognized, as reported by the service.
+    Other(String),
+}
+
+/// A record of a device being blacklisted by a carrier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlacklistReport {
+    pub carrier: Option<String>,
+    pub country: Option<String>,
+    pub reason: Option<BlacklistReason>,
+    pub reported_at: Option<NaiveDate>,
+}
+
+/// The blacklist status of a device, as reported by one of the blacklist checks.
+///
+/// The `Display` implementation is a sentence which can be shown to customers, such as
+/// `"This device is blacklisted: reported stolen by Verizon (United States) on 2024-01-05."`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlacklistStatus {
+    pub imei: Imei,
+    pub tier: BlacklistTier,
+    pub state: BlacklistState,
+    /// The records of the device being blacklisted, which may be empty even if it is blacklisted.
+    pub reports: Vec<BlacklistReport>,
+}
+
+impl BlacklistTier {
+    /// Retrieve the service which checks the blacklist at this tier.
+    pub fn service(&self) -> Service {
+        match self {
+            BlacklistTier::Simple => Service::BlacklistSimpleCheck,
+            BlacklistTier::Premium => Service::BlacklistPremiumCheck,
+        }
+    }
+}
+
+impl BlacklistReason {
+    /// Parse a reason as reported by the service, returning `None` if it is empty.
+    pub fn parse(reason: &str) -> Option<Self> {
+        let reason = reason.trim();
+        let lowercase = reason.to_lowercase();
+        if reason.is_empty() {
+            None
+        } else if lowercase.contains("stolen") || lowercase.contains("theft") {
+            Some(BlacklistReason::Stolen)
+        } else if lowercase.contains("lost") {
+            Some(BlacklistReason::Lost)
+        } else if ["unpaid", "non-payment", "nonpayment", "debt", "financ"]
+            .iter()
+            .any(|word| lowercase.contains(word))
+        {
+            Some(BlacklistReason::Unpaid)
+        } else {
+            Some(BlacklistReason::Other(reason.to_owned()))
+        }
+    }
+}
+
+impl BlacklistReport {
+    fn from_api(record: ApiBlacklistRecord) -> Option<Self> {
+        let report = Self {
+            carrier: record.carrier.filter(|carrier| !carrier.trim().is_empty()),
+            country: record.country.filter(|country| !country.trim().is_empty()),
+            reason: record.reason.as_deref().and_then(BlacklistReason::parse),
+            reported_at: parse_api_date(record.reported_at.as_deref()),
+        };
+        // * A record without any fields is how the simple check reports that there is nothing to report
+        (report.carrier.is_some()
+            || report.country.is_some()
+            || report.reason.is_some()
+            || report.reported_at.is_some())
+        .then_some(report)
+    }
+}
+
+impl BlacklistStatus {
+    pub(crate) fn from_api(imei: Imei, tier: BlacklistTier, status: ApiBlacklistStatus) -> Self {
+        let reports: Vec<_> = std::iter::once(status.record)
+            .chain(status.records)
+            .filter_map(BlacklistReport::from_api)
+            .collect();
+        let blacklisted = status
+            .blacklist
+            .as_ref()
+            .and_then(|flag| flag.value_with(BLACKLISTED_WORDS, CLEAN_WORDS));
+        Self {
+            imei,
+            tier,
+            state: match blacklisted {
+                Some(true) => BlacklistState::Blacklisted,
+                Some(false) => BlacklistState::Clean,
+                // * Services which do not report the status still report why a device is blacklisted
+                None if !reports.is_empty() => BlacklistState::Blacklisted,
+                None => BlacklistState::Unknown,
+            },
+            reports,
+        }
+    }
+
+    /// Check whether the device is blacklisted. Devices with an unknown status are not considered blacklisted,
+    /// so check [`BlacklistStatus::state`] directly to treat them as blacklisted.
+    pub fn is_blacklisted(&self) -> bool {
+        self.state == BlacklistState::Blacklisted
+    }
+}
+
+impl Display for BlacklistTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BlacklistTier::Simple => "Simple",
+            BlacklistTier::Premium => "Premium",
+        })
+    }
+}
+
+impl Display for BlacklistReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BlacklistReason::Lost => "lost",
+            BlacklistReason::Stolen => "stolen",
+            BlacklistReason::Unpaid => "for an unpaid balance",
+            BlacklistReason::Other(reason) => reason,
+        })
+    }
+}
+
+impl Display for BlacklistReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("reported")?;
+        match &self.reason {
+            Some(BlacklistReason::Other(reason)) => write!(f, " ({reason})")?,
+            Some(reason) => write!(f, " {reason}")?,
+            None => {}
+        }
+        match (&self.carrier, &self.country) {
+            (Some(carrier), Some(country)) => write!(f, " by {carrier} ({country})")?,
+            (Some(carrier), None) => write!(f, " by {carrier}")?,
+            (None, Some(country)) => write!(f, " in {country}")?,
+            (None, None) => {}
+        }
+        if let Some(reported_at) = self.reported_at {
+            write!(f, " on {reported_at}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for BlacklistStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.state {
+            BlacklistState::Clean => f.write_str("This device is not blacklisted."),
+            BlacklistState::Unknown => {
+                f.write_str("The blacklist status of this device could not be determined.")
+            }
+            BlacklistState::Blacklisted if self.reports.is_empty() => {
+                f.write_str("This device is blacklisted.")
+            }
+            BlacklistState::Blacklisted => {
+                f.write_str("This device is blacklisted: ")?;
+                for (i, report) in self.reports.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{report}")?;
+                }
+                f.write_str(".")
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::sim::FromStrVisitor;
+use crate::api::{ApiCarrierStatus, ApiFlag};
+use crate::wrapper::{Imei, ImeiWrapperError, Msisdn, BLACKLISTED_WORDS, CLEAN_WORDS};
+use crate::{find_operator, find_operator_by_name, Operator, Service};
+
+/// Words which services use for a device which can be activated, in addition to the usual yes/no values.
+const ELIGIBLE_WORDS: &[&str] = &["eligible"];
+/// Words which services use for a device which cannot be activated, in addition to the usual yes/no values.
+const INELIGIBLE_WORDS: &[&str] = &["ineligible", "not eligible"];
+
+/// Which of the carrier checks to use. The carrier lookup works for any carrier, while the US carrier checks
+/// query Verizon and T-Mobile directly, so they are more reliable for devices from those carriers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CarrierCheck {
+    Lookup,
+    Verizon,
+    TMobile,
+}
+
+/// An identifier which is accepted by the carrier checks. Only the carrier lookup accepts phone numbers.
+/// Phone numbers are displayed and serialized with a leading `+`, which tells them apart from IMEIs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarrierIdentifier {
+    Imei(Imei),
+    PhoneNumber(Msisdn),
+}
+
+/// A carrier reported by a carrier check: an operator from the embedded operator table if the reported name
+/// matches one, or the name as reported otherwise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "SerializedCarrier", into = "SerializedCarrier")]
+pub enum Carrier {
+    Operator(&'static Operator),
+    Unlisted(String),
+}
+
+/// The serialized form of a [`Carrier`]. Operators are serialized with their codes, and are deserialized as
+/// unlisted carriers if the embedded operator table no longer has them.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerializedCarrier {
+    Operator {
+        mobile_country_code: String,
+        mobile_network_code: String,
+        name: String,
+    },
+    Unlisted(String),
+}
+
+/// The status of a device with its carrier, as reported by one of the carrier checks. The financing, blacklist,
+/// and eligibility fields refer to the network of the checked carrier, or of the original carrier for the lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierStatus {
+    pub identifier: CarrierIdentifier,
+    pub check: CarrierCheck,
+    /// The carrier which first sold the device, or which the phone number belongs to for phone number lookups.
+    pub original_carrier: Option<Carrier>,
+    /// The carrier the device is locked to, if it is locked.
+    pub locked_carrier: Option<Carrier>,
+    /// The ISO code of the country of the carrier, such as `"US"`.
+    pub country_code: Option<String>,
+    pub sim_locked: Option<bool>,
+    /// Whether the device was bought with a financing or installment plan.
+    pub financed: Option<bool>,
+    /// Whether the account the device is on has an unpaid balance, which usually stops it from being unlocked.
+    pub unpaid_balance: Option<bool>,
+    pub blacklisted: Option<bool>,
+    /// Whether the device can be activated on the network.
+    pub activation_eligible: Option<bool>,
+}
+
+impl CarrierCheck {
+    /// Retrieve the service which performs this check.
+    pub fn service(&self) -> Service {
+        match self {
+            CarrierCheck::Lookup => Service::CarrierLookup,
+            CarrierCheck::Verizon => Service::VerizonUsaCheck,
+            CarrierCheck::TMobile => Service::TMobileUsaCheck,
+        }
+    }
+
+    /// Look up the carrier this check queries in the embedded operator table, which is `None` for the lookup.
+    pub fn operator(&self) -> Option<&'static Operator> {
+        match self {
+            CarrierCheck::Lookup => None,
+            CarrierCheck::Verizon => find_operator_by_name("Verizon", Some("US")),
+            CarrierCheck::TMobile => find_operator_by_name("T-Mobile", Some("US")),
+        }
+    }
+}
+
+impl Carrier {
+    /// Retrieve the name of the carrier, which is the name from the embedded operator table if it is in it.
+    pub fn name(&self) -> &str {
+        match self {
+            Carrier::Operator(operator) => operator.name,
+            Carrier::Unlisted(name) => name,
+        }
+    }
+
+    /// Retrieve the operator from the embedded operator table, if the carrier is in it.
+    pub fn operator(&self) -> Option<&'static Operator> {
+        match self {
+            Carrier::Operator(operator) => Some(operator),
+            Carrier::Unlisted(_) => None,
+        }
+    }
+}
+
+impl CarrierStatus {
+    pub(crate) fn from_api(
+        identifier: CarrierIdentifier,
+        check: CarrierCheck,
+        status: ApiCarrierStatus,
+    ) -> Self {
+        // * Only ISO codes are kept, since some services report the name of the country instead
+        let country_code = match check {
+            CarrierCheck::Lookup => status
+                .country_code
+                .map(|code| code.trim().to_ascii_uppercase())
+                .filter(|code| code.len() == 2 && code.chars().all(|c| c.is_ascii_uppercase()))
+                .or_else(|| identifier.country_code().map(str::to_owned)),
+            CarrierCheck::Verizon | CarrierCheck::TMobile => Some("US".to_owned()),
+        };
+        let normalize = |name: Option<String>| {
+            let name = name.filter(|name| !name.trim().is_empty())?;
+            Some(
+                find_operator_by_name(&name, country_code.as_deref())
+                    .map(Carrier::Operator)
+                    .unwrap_or(Carrier::Unlisted(name)),
+            )
+        };
+        let flag = |flag: Option<ApiFlag>| flag.as_ref().and_then(ApiFlag::value);
+
+        Self {
+            identifier,
+            check,
+            original_carrier: normalize(status.original_carrier),
+            locked_carrier: normalize(status.locked_carrier),
+            sim_locked: flag(status.sim_locked),
+            financed: flag(status.financed),
+            unpaid_balance: flag(status.unpaid_balance),
+            blacklisted: status
+                .blacklist
+                .and_then(|flag| flag.value_with(BLACKLISTED_WORDS, CLEAN_WORDS)),
+            activation_eligible: status
+                .activation_eligible
+                .and_then(|flag| flag.value_with(ELIGIBLE_WORDS, INELIGIBLE_WORDS)),
+            country_code,
+        }
+    }
+
+    /// Retrieve the original carrier from the embedded operator table, if it is in it.
+    pub fn original_operator(&self) -> Option<&'static Operator> {
+        self.original_carrier.as_ref()?.operator()
+    }
+
+    /// Retrieve the carrier the device is locked to from the embedded operator table, if it is in it.
+    pub fn locked_operator(&self) -> Option<&'static Operator> {
+        self.locked_carrier.as_ref()?.operator()
+    }
+
+    /// Check whether the service reported anything which would stop the device from being used on another
+    /// account: a SIM lock, financing, an unpaid balance, a blacklisting, or ineligibility for activation.
+    /// Fields which were not reported are not considered issues.
+    pub fn has_reported_issues(&self) -> bool {
+        [
+            self.sim_locked,
+            self.financed,
+            self.unpaid_balance,
+            self.blacklisted,
+            self.activation_eligible.map(|eligible| !eligible),
+        ]
+        .contains(&Some(true))
+    }
+}
+
+impl Display for CarrierCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CarrierCheck::Lookup => "Carrier Lookup",
+            CarrierCheck::Verizon => "Verizon",
+            CarrierCheck::TMobile => "T-Mobile",
+        })
+    }
+}
+
+impl CarrierIdentifier {
+    /// Retrieve the ISO code of the country of a phone number, if its calling code is only used by one country
+    /// in the embedded country table. IMEIs do not belong to a country.
+    fn country_code(&self) -> Option<&'static str> {
+        let CarrierIdentifier::PhoneNumber(phone_number) = self else {
+            return None;
+        };
+
+        let mut countries = phone_number.countries();
+        match (countries.next(), countries.next()) {
+            (Some(country), None) => Some(country.iso_code),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for CarrierIdentifier {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 15 && s.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(CarrierIdentifier::Imei(Imei::from_str(s)?))
+        } else {
+            Ok(CarrierIdentifier::PhoneNumber(Msisdn::from_str(s)?))
+        }
+    }
+}
+
+impl TryFrom<&str> for CarrierIdentifier {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl From<Imei> for CarrierIdentifier {
+    fn from(imei: Imei) -> Self {
+        CarrierIdentifier::Imei(imei)
+    }
+}
+
+impl From<Msisdn> for CarrierIdentifier {
+    fn from(phone_number: Msisdn) -> Self {
+        CarrierIdentifier::PhoneNumber(phone_number)
+    }
+}
+
+impl Display for CarrierIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarrierIdentifier::Imei(imei) => imei.fmt(f),
+            CarrierIdentifier::PhoneNumber(phone_number) => phone_number.fmt(f),
+        }
+    }
+}
+
+impl Serialize for CarrierIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CarrierIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an IMEI or an E.164 phone number"))
+    }
+}
+
+impl Display for Carrier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<SerializedCarrier> for Carrier {
+    fn from(carrier: SerializedCarrier) -> Self {
+        match carrier {
+            SerializedCarrier::Operator {
+                mobile_country_code,
+                mobile_network_code,
+                name,
+            } => find_operator(&mobile_country_code, &mobile_network_code)
+                .map(Carrier::Operator)
+                .unwrap_or(Carrier::Unlisted(name)),
+            SerializedCarrier::Unlisted(name) => Carrier::Unlisted(name),
+        }
+    }
+}
+
+impl From<Carrier> for SerializedCarrier {
+    fn from(carrier: Carrier) -> Self {
+        match carrier {
+            Carrier::Operator(operator) => SerializedCarrier::Operator {
+                mobile_country_code: operator.mobile_country_code.to_owned(),
+                mobile_network_code: operator.mobile_network_code.to_owned(),
+                name: operator.name.to_owned(),
+            },
+            Carrier::Unlisted(name) => SerializedCarrier::Unlisted(name),
+        }
+    }
+}
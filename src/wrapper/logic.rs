@@ -1,6 +1,64 @@
-use crate::api::BASIC_IMEI_CHECK_SID;
+use std::fmt::Display;
+
+use serde::Deserialize;
+
+use crate::api::{
+    ApiAppleCarrierLockFmiStatus, ApiAppleSimlock, ApiAppleSoldByWarranty, ApiAppleWarranty,
+    ApiBlacklistPremiumStatus, ApiBlacklistSimpleStatus, ApiCarrierLookupStatus, ApiGoogleInfo,
+    ApiLgInfo, ApiMacAddressInfo, ApiPhoneInfo, ApiSamsungInfo, ApiSamsungKnoxInfo, ApiSonyInfo,
+    ApiTMobileStatus, ApiVerizonStatus, ApiXiaomiInfo, ApiXiaomiMiLockInfo,
+};
 use crate::error::{Result, ServiceCheckError};
-use crate::wrapper::{Imei, PhoneInfo, Tac};
+use crate::wrapper::{
+    AppleSimlock, AppleWarranty, BlacklistStatus, BlacklistTier, CarrierCheck, CarrierStatus,
+    CheckRecord, DeviceIdentifier, FmiStatus, Imei, MacAddress, MacAddressInfo, ManufacturerInfo,
+    Msisdn, PhoneInfo, SamsungInfo, SamsungKnoxInfo, Tac, XiaomiInfo, XiaomiMiLockStatus,
+};
+use crate::{Brand, SanityFlag, SanityFlags, Service, ServiceCheck};
+
+/// The sanity flags which only depend on the TAC of an IMEI.
+const TAC_SANITY_FLAGS: [SanityFlag; 3] = [
+    SanityFlag::AllZeros,
+    SanityFlag::TestImei,
+    SanityFlag::UnknownReportingBody,
+];
+
+/// Options for lookups which take an IMEI, to avoid spending tokens on inputs which are unlikely to return anything useful.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LookupOptions {
+    /// Refuse to make the request if the IMEI has any of these [`SanityFlags`], returning
+    /// [`ServiceCheckError::SuspiciousImei`] instead. Empty by default, so nothing is refused.
+    pub refuse_flags: SanityFlags,
+}
+
+impl LookupOptions {
+    /// Check the IMEI against the options before a request is made, returning its flags if it should be refused.
+    pub(crate) fn refused_flags(&self, imei: &Imei) -> Option<SanityFlags> {
+        let flags = imei.sanity_flags();
+        flags.intersects(self.refuse_flags).then_some(flags)
+    }
+
+    /// Check the IMEI generated from a TAC against the options, ignoring the flags which its zero serial number causes.
+    pub(crate) fn refused_tac_flags(&self, imei: &Imei) -> Option<SanityFlags> {
+        let flags: SanityFlags = imei
+            .sanity_flags()
+            .iter()
+            .filter(|flag| TAC_SANITY_FLAGS.contains(flag))
+            .collect();
+        flags.intersects(self.refuse_flags).then_some(flags)
+    }
+
+    /// Check a device identifier against the options, which only refuse IMEIs since serial numbers have no sanity flags.
+    pub(crate) fn refused_identifier_flags(
+        &self,
+        identifier: &DeviceIdentifier,
+    ) -> Option<SanityFlags> {
+        match identifier {
+            DeviceIdentifier::Imei(imei) => self.refused_flags(imei),
+            DeviceIdentifier::AppleSerial(_) => None,
+        }
+    }
+}
 
 /// Get the basic information about a device (make and model) using its IMEI.
 /// The IMEI is required to be a string because if it was a numerical type, leading zeroes would be truncated.
@@ -11,13 +69,36 @@ use crate::wrapper::{Imei, PhoneInfo, Tac};
 /// - The request could not be built or parsed due to a logic error within this crate or `reqwest`
 /// - The IMEI.info API has been updated with a breaking change since the last crate release
 pub async fn get_imei_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<PhoneInfo> {
+    get_imei_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the basic information about a device using its IMEI, as with [`get_imei_info`], but check the IMEI against
+/// the [`LookupOptions`] first. This returns [`ServiceCheckError::SuspiciousImei`] without making a request if the
+/// IMEI has any of the sanity flags the options refuse.
+pub async fn get_imei_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<PhoneInfo> {
+    Ok(get_imei_info_detailed(api_key, imei, options).await?.result)
+}
+
+/// Get the basic information about a device using its IMEI, as with [`get_imei_info_with_options`], along with the
+/// full record of the check.
+pub async fn get_imei_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<PhoneInfo>> {
     let Ok(imei) = imei.try_into() else {
         return Err(ServiceCheckError::InvalidImeiNumber);
     };
 
-    let response =
-        crate::api::check_imei_with_service(BASIC_IMEI_CHECK_SID, api_key, &imei).await?;
-    Ok(response.result.into())
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    check_phone_info(api_key, &imei).await
 }
 
 /// Get the basic information about a device (make and model) using its TAC.
@@ -27,18 +108,775 @@ pub async fn get_imei_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<Ph
 /// The call to the IMEI.info API used here is the same as in [`get_imei_info`], it simply uses the TAC to generate
 /// a generic IMEI to be checked.
 ///
-/// This method will return an error in the following cases, roughly arranged in order of likelihood:
-/// - The API key and/or the TAC are invalid
-/// - The IMEI.info API returns a "pending" (202) response, requiring a second request to retrieve the information
-/// - The request could not be built or parsed due to a logic error within this crate or `reqwest`
-/// - The IMEI.info API has been updated with a breaking change since the last crate release
+/// This method will return an error in the same cases as [`get_imei_info`], with the TAC in place of the IMEI.
 pub async fn get_tac_info(api_key: &str, tac: impl TryInto<Tac>) -> Result<PhoneInfo> {
+    get_tac_info_with_options(api_key, tac, &LookupOptions::default()).await
+}
+
+/// Get the basic information about a device using its TAC, as with [`get_tac_info`], but check the TAC against the
+/// [`LookupOptions`] first. The IMEI generated from a TAC always has a zero serial number, so only the sanity flags
+/// which depend on the TAC are considered.
+pub async fn get_tac_info_with_options(
+    api_key: &str,
+    tac: impl TryInto<Tac>,
+    options: &LookupOptions,
+) -> Result<PhoneInfo> {
+    Ok(get_tac_info_detailed(api_key, tac, options).await?.result)
+}
+
+/// Get the basic information about a device using its TAC, as with [`get_tac_info_with_options`], along with the
+/// full record of the check.
+pub async fn get_tac_info_detailed(
+    api_key: &str,
+    tac: impl TryInto<Tac>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<PhoneInfo>> {
     let Ok(tac) = tac.try_into() else {
         return Err(ServiceCheckError::InvalidImeiNumber);
     };
 
+    let imei = Imei::from(tac);
+    if let Some(flags) = options.refused_tac_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    check_phone_info(api_key, &imei).await
+}
+
+/// Get the information about a device's network interface (vendor, and sometimes model) using its MAC address.
+/// This is the only way to look up devices without a cellular modem, such as Wi-Fi-only tablets.
+/// Locally administered (randomized) addresses do not identify the vendor, so they are unlikely to return anything useful.
+///
+/// This method will return an error in the same cases as [`get_imei_info`], with the
+/// MAC address in place of the IMEI.
+pub async fn get_mac_address_info(
+    api_key: &str,
+    mac_address: impl TryInto<MacAddress>,
+) -> Result<MacAddressInfo> {
+    Ok(get_mac_address_info_detailed(api_key, mac_address)
+        .await?
+        .result)
+}
+
+/// Get the information about a device's network interface, as with [`get_mac_address_info`], along with the
+/// full record of the check.
+pub async fn get_mac_address_info_detailed(
+    api_key: &str,
+    mac_address: impl TryInto<MacAddress>,
+) -> Result<CheckRecord<MacAddressInfo>> {
+    let Ok(mac_address) = mac_address.try_into() else {
+        return Err(ServiceCheckError::InvalidMacAddress);
+    };
+
+    let record = check_service_detailed::<ApiMacAddressInfo>(api_key, &mac_address).await?;
+    Ok(record.map(|info| MacAddressInfo::from_api(mac_address, info)))
+}
+
+/// Get the Find My iPhone status of an Apple device using its IMEI or serial number, along with whether it is
+/// locked to a carrier. A device with Find My iPhone turned on is protected by Activation Lock, so it cannot be
+/// set up by anyone else without the owner's Apple ID.
+///
+/// This method will return an error in the same cases as [`get_imei_info`], with the
+/// IMEI or serial number in place of the IMEI.
+pub async fn get_apple_carrier_lock_fmi_status(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+) -> Result<FmiStatus> {
+    get_apple_carrier_lock_fmi_status_with_options(
+        api_key,
+        imei_or_serial,
+        &LookupOptions::default(),
+    )
+    .await
+}
+
+/// Get the Find My iPhone and carrier lock status of an Apple device, as with
+/// [`get_apple_carrier_lock_fmi_status`], but check the identifier against the [`LookupOptions`] first if it is an
+/// IMEI, as with [`get_imei_info_with_options`].
+pub async fn get_apple_carrier_lock_fmi_status_with_options(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<FmiStatus> {
+    Ok(
+        get_apple_carrier_lock_fmi_status_detailed(api_key, imei_or_serial, options)
+            .await?
+            .result,
+    )
+}
+
+/// Get the Find My iPhone and carrier lock status of an Apple device, as with
+/// [`get_apple_carrier_lock_fmi_status_with_options`], along with the full record of the check.
+pub async fn get_apple_carrier_lock_fmi_status_detailed(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<FmiStatus>> {
+    let Ok(identifier) = imei_or_serial.try_into() else {
+        return Err(ServiceCheckError::InvalidDeviceIdentifier);
+    };
+
+    if let Some(flags) = options.refused_identifier_flags(&identifier) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record =
+        check_service_detailed::<ApiAppleCarrierLockFmiStatus>(api_key, &identifier).await?;
+    Ok(record.map(|status| FmiStatus::from_api(identifier, status)))
+}
+
+/// Get the warranty coverage of an Apple device using its IMEI or serial number, including the purchase date,
+/// AppleCare coverage, and whether it is a replacement or refurbished device.
+///
+/// This method will return an error in the same cases as [`get_imei_info`], with the
+/// IMEI or serial number in place of the IMEI.
+pub async fn get_apple_warranty(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+) -> Result<AppleWarranty> {
+    get_apple_warranty_with_options(api_key, imei_or_serial, &LookupOptions::default()).await
+}
+
+/// Get the warranty coverage of an Apple device, as with [`get_apple_warranty`], but check the identifier against
+/// the [`LookupOptions`] first if it is an IMEI, as with [`get_imei_info_with_options`].
+pub async fn get_apple_warranty_with_options(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<AppleWarranty> {
+    Ok(
+        get_apple_warranty_detailed(api_key, imei_or_serial, options)
+            .await?
+            .result,
+    )
+}
+
+/// Get the warranty coverage of an Apple device, as with [`get_apple_warranty_with_options`], along with the full
+/// record of the check.
+pub async fn get_apple_warranty_detailed(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<AppleWarranty>> {
+    let Ok(identifier) = imei_or_serial.try_into() else {
+        return Err(ServiceCheckError::InvalidDeviceIdentifier);
+    };
+
+    if let Some(flags) = options.refused_identifier_flags(&identifier) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiAppleWarranty>(api_key, &identifier).await?;
+    Ok(record.map(|warranty| AppleWarranty::from_api(identifier, warranty)))
+}
+
+/// Get the warranty coverage of an Apple device, as with [`get_apple_warranty`], along with who sold it and in which country.
+pub async fn get_apple_sold_by_warranty(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+) -> Result<AppleWarranty> {
+    get_apple_sold_by_warranty_with_options(api_key, imei_or_serial, &LookupOptions::default())
+        .await
+}
+
+/// Get the warranty coverage and seller of an Apple device, as with [`get_apple_sold_by_warranty`], but check the
+/// identifier against the [`LookupOptions`] first if it is an IMEI, as with [`get_imei_info_with_options`].
+pub async fn get_apple_sold_by_warranty_with_options(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<AppleWarranty> {
+    Ok(
+        get_apple_sold_by_warranty_detailed(api_key, imei_or_serial, options)
+            .await?
+            .result,
+    )
+}
+
+/// Get the warranty coverage and seller of an Apple device, as with [`get_apple_sold_by_warranty_with_options`],
+/// along with the full record of the check.
+pub async fn get_apple_sold_by_warranty_detailed(
+    api_key: &str,
+    imei_or_serial: impl TryInto<DeviceIdentifier>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<AppleWarranty>> {
+    let Ok(identifier) = imei_or_serial.try_into() else {
+        return Err(ServiceCheckError::InvalidDeviceIdentifier);
+    };
+
+    if let Some(flags) = options.refused_identifier_flags(&identifier) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiAppleSoldByWarranty>(api_key, &identifier).await?;
+    Ok(record.map(|warranty| AppleWarranty::from_api_with_sold_by(identifier, warranty)))
+}
+
+/// Get the SIM lock of an Apple device using its IMEI, including the carrier policies which determine which
+/// carrier it is locked to.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_apple_simlock(api_key: &str, imei: impl TryInto<Imei>) -> Result<AppleSimlock> {
+    get_apple_simlock_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the SIM lock of an Apple device, as with [`get_apple_simlock`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_apple_simlock_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<AppleSimlock> {
+    Ok(get_apple_simlock_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the SIM lock of an Apple device, as with [`get_apple_simlock_with_options`], along with the full record of
+/// the check.
+pub async fn get_apple_simlock_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<AppleSimlock>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiAppleSimlock>(api_key, &imei).await?;
+    Ok(record.map(|simlock| AppleSimlock::from_api(imei, simlock)))
+}
+
+/// Get the information about a Samsung device using its IMEI, including its model code, region, manufacture date,
+/// and warranty. Use [`SamsungInfo::check_model`] with the result of [`get_tac_info`] to detect swapped boards.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_samsung_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<SamsungInfo> {
+    get_samsung_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about a Samsung device, as with [`get_samsung_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_samsung_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<SamsungInfo> {
+    Ok(get_samsung_info_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the information about a Samsung device, as with [`get_samsung_info_with_options`], along with the full
+/// record of the check.
+pub async fn get_samsung_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<SamsungInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiSamsungInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| SamsungInfo::from_api(imei, info)))
+}
+
+/// Get the Knox status of a Samsung device using its IMEI, including whether it is locked by Knox Guard or
+/// enrolled in an organization's device management.
+///
+/// This method will return an error in the same cases as [`get_samsung_info`].
+pub async fn get_samsung_knox_info(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+) -> Result<SamsungKnoxInfo> {
+    get_samsung_knox_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the Knox status of a Samsung device, as with [`get_samsung_knox_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_samsung_knox_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<SamsungKnoxInfo> {
+    Ok(get_samsung_knox_info_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the Knox status of a Samsung device, as with [`get_samsung_knox_info_with_options`], along with the full
+/// record of the check.
+pub async fn get_samsung_knox_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<SamsungKnoxInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiSamsungKnoxInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| SamsungKnoxInfo::from_api(imei, info)))
+}
+
+/// Get the information about a Xiaomi device using its IMEI, including its model number, firmware region, and
+/// activation date.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_xiaomi_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<XiaomiInfo> {
+    get_xiaomi_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about a Xiaomi device, as with [`get_xiaomi_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_xiaomi_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<XiaomiInfo> {
+    Ok(get_xiaomi_info_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the information about a Xiaomi device, as with [`get_xiaomi_info_with_options`], along with the full record
+/// of the check.
+pub async fn get_xiaomi_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<XiaomiInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiXiaomiInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| XiaomiInfo::from_api(imei, info)))
+}
+
+/// Get the Mi Account lock status of a Xiaomi device using its IMEI. A device which is locked to a Mi Account
+/// cannot be set up by anyone else without the owner's account.
+///
+/// This method will return an error in the same cases as [`get_xiaomi_info`].
+pub async fn get_xiaomi_mi_lock_status(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+) -> Result<XiaomiMiLockStatus> {
+    get_xiaomi_mi_lock_status_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the Mi Account lock status of a Xiaomi device, as with [`get_xiaomi_mi_lock_status`], but check the IMEI
+/// against the [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_xiaomi_mi_lock_status_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<XiaomiMiLockStatus> {
+    Ok(get_xiaomi_mi_lock_status_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the Mi Account lock status of a Xiaomi device, as with [`get_xiaomi_mi_lock_status_with_options`], along
+/// with the full record of the check.
+pub async fn get_xiaomi_mi_lock_status_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<XiaomiMiLockStatus>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiXiaomiMiLockInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| XiaomiMiLockStatus::from_api(imei, info)))
+}
+
+/// Get the information about a Google device using its IMEI, including its exact SKU, region, and warranty.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_google_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<ManufacturerInfo> {
+    get_google_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about a Google device, as with [`get_google_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_google_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<ManufacturerInfo> {
+    Ok(get_google_info_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the information about a Google device, as with [`get_google_info_with_options`], along with the full record
+/// of the check.
+pub async fn get_google_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<ManufacturerInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiGoogleInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| ManufacturerInfo::from_api_google(imei, info)))
+}
+
+/// Get the information about an LG device using its IMEI, including its exact model, buyer code, and warranty.
+///
+/// This method will return an error in the same cases as [`get_google_info`].
+pub async fn get_lg_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<ManufacturerInfo> {
+    get_lg_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about an LG device, as with [`get_lg_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_lg_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<ManufacturerInfo> {
+    Ok(get_lg_info_detailed(api_key, imei, options).await?.result)
+}
+
+/// Get the information about an LG device, as with [`get_lg_info_with_options`], along with the full record of the
+/// check.
+pub async fn get_lg_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<ManufacturerInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiLgInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| ManufacturerInfo::from_api_lg(imei, info)))
+}
+
+/// Get the information about a Sony device using its IMEI, including its exact model, customization, and warranty.
+///
+/// This method will return an error in the same cases as [`get_google_info`].
+pub async fn get_sony_info(api_key: &str, imei: impl TryInto<Imei>) -> Result<ManufacturerInfo> {
+    get_sony_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about a Sony device, as with [`get_sony_info`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_sony_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<ManufacturerInfo> {
+    Ok(get_sony_info_detailed(api_key, imei, options).await?.result)
+}
+
+/// Get the information about a Sony device, as with [`get_sony_info_with_options`], along with the full record of
+/// the check.
+pub async fn get_sony_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<ManufacturerInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = check_service_detailed::<ApiSonyInfo>(api_key, &imei).await?;
+    Ok(record.map(|info| ManufacturerInfo::from_api_sony(imei, info)))
+}
+
+/// Get the information about a device from its manufacturer using its IMEI, picking the info check from the
+/// manufacturer reported by [`get_imei_info`]. This makes two requests, though the first is free.
+///
+/// This method will return an error in the same cases as [`get_google_info`], or
+/// [`ServiceCheckError::UnsupportedManufacturer`] if the manufacturer has no info check (see [`Brand::info_service`]).
+pub async fn get_manufacturer_info(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+) -> Result<ManufacturerInfo> {
+    get_manufacturer_info_with_options(api_key, imei, &LookupOptions::default()).await
+}
+
+/// Get the information about a device from its manufacturer, as with [`get_manufacturer_info`], but check the IMEI
+/// against the [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_manufacturer_info_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<ManufacturerInfo> {
+    Ok(get_manufacturer_info_detailed(api_key, imei, options)
+        .await?
+        .result)
+}
+
+/// Get the information about a device from its manufacturer, as with [`get_manufacturer_info_with_options`], along
+/// with the full record of the manufacturer's check.
+pub async fn get_manufacturer_info_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    options: &LookupOptions,
+) -> Result<CheckRecord<ManufacturerInfo>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let PhoneInfo { manufacturer, .. } = check_phone_info(api_key, &imei).await?.result;
+    match Brand::from_manufacturer(&manufacturer) {
+        Some(Brand::Samsung) => Ok(get_samsung_info_detailed(api_key, imei, options)
+            .await?
+            .map(ManufacturerInfo::from)),
+        Some(Brand::Google) => get_google_info_detailed(api_key, imei, options).await,
+        Some(Brand::Lg) => get_lg_info_detailed(api_key, imei, options).await,
+        Some(Brand::Sony) => get_sony_info_detailed(api_key, imei, options).await,
+        Some(Brand::Xiaomi) => Ok(get_xiaomi_info_detailed(api_key, imei, options)
+            .await?
+            .map(ManufacturerInfo::from)),
+        Some(Brand::Apple) | None => {
+            Err(ServiceCheckError::UnsupportedManufacturer { manufacturer })
+        }
+    }
+}
+
+/// Get the blacklist status of a device using its IMEI, including which carriers reported it and why. The
+/// premium tier covers more carriers and countries than the simple tier, but costs more.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_blacklist_status(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    tier: BlacklistTier,
+) -> Result<BlacklistStatus> {
+    get_blacklist_status_with_options(api_key, imei, tier, &LookupOptions::default()).await
+}
+
+/// Get the blacklist status of a device, as with [`get_blacklist_status`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_blacklist_status_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    tier: BlacklistTier,
+    options: &LookupOptions,
+) -> Result<BlacklistStatus> {
+    Ok(get_blacklist_status_detailed(api_key, imei, tier, options)
+        .await?
+        .result)
+}
+
+/// Get the blacklist status of a device, as with [`get_blacklist_status_with_options`], along with the full record
+/// of the check.
+pub async fn get_blacklist_status_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    tier: BlacklistTier,
+    options: &LookupOptions,
+) -> Result<CheckRecord<BlacklistStatus>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = match tier {
+        BlacklistTier::Simple => check_service_detailed::<ApiBlacklistSimpleStatus>(api_key, &imei)
+            .await?
+            .map(|status| status.0),
+        BlacklistTier::Premium => {
+            check_service_detailed::<ApiBlacklistPremiumStatus>(api_key, &imei)
+                .await?
+                .map(|status| status.0)
+        }
+    };
+    Ok(record.map(|status| BlacklistStatus::from_api(imei, tier, status)))
+}
+
+/// Get the status of a device with its carrier using its IMEI, including the carrier it is locked to, whether
+/// it is financed or has an unpaid balance, whether the carrier has blacklisted it, and whether it can be activated.
+/// The same result is returned by every [`CarrierCheck`], so devices can be checked against the carrier lookup
+/// first and then against the US carrier they are locked to.
+///
+/// This method will return an error in the same cases as [`get_imei_info`].
+pub async fn get_carrier_status(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    check: CarrierCheck,
+) -> Result<CarrierStatus> {
+    get_carrier_status_with_options(api_key, imei, check, &LookupOptions::default()).await
+}
+
+/// Get the status of a device with its carrier, as with [`get_carrier_status`], but check the IMEI against the
+/// [`LookupOptions`] first, as with [`get_imei_info_with_options`].
+pub async fn get_carrier_status_with_options(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    check: CarrierCheck,
+    options: &LookupOptions,
+) -> Result<CarrierStatus> {
+    Ok(get_carrier_status_detailed(api_key, imei, check, options)
+        .await?
+        .result)
+}
+
+/// Get the status of a device with its carrier, as with [`get_carrier_status_with_options`], along with the full
+/// record of the check.
+pub async fn get_carrier_status_detailed(
+    api_key: &str,
+    imei: impl TryInto<Imei>,
+    check: CarrierCheck,
+    options: &LookupOptions,
+) -> Result<CheckRecord<CarrierStatus>> {
+    let Ok(imei) = imei.try_into() else {
+        return Err(ServiceCheckError::InvalidImeiNumber);
+    };
+
+    if let Some(flags) = options.refused_flags(&imei) {
+        return Err(ServiceCheckError::SuspiciousImei { flags });
+    }
+
+    let record = match check {
+        CarrierCheck::Lookup => check_service_detailed::<ApiCarrierLookupStatus>(api_key, &imei)
+            .await?
+            .map(|status| status.0),
+        CarrierCheck::Verizon => check_service_detailed::<ApiVerizonStatus>(api_key, &imei)
+            .await?
+            .map(|status| status.0),
+        CarrierCheck::TMobile => check_service_detailed::<ApiTMobileStatus>(api_key, &imei)
+            .await?
+            .map(|status| status.0),
+    };
+    Ok(record.map(|status| CarrierStatus::from_api(imei.into(), check, status)))
+}
+
+/// Get the carrier of a phone number using the carrier lookup, which reports the carrier the number belongs to
+/// as its original carrier. There is no options form, since [`LookupOptions`] only apply to IMEIs.
+///
+/// This method will return an error in the same cases as [`get_carrier_status`], except that an invalid phone
+/// number is reported instead of an invalid IMEI.
+pub async fn get_carrier_status_by_phone_number(
+    api_key: &str,
+    phone_number: impl TryInto<Msisdn>,
+) -> Result<CarrierStatus> {
+    Ok(
+        get_carrier_status_by_phone_number_detailed(api_key, phone_number)
+            .await?
+            .result,
+    )
+}
+
+/// Get the carrier of a phone number, as with [`get_carrier_status_by_phone_number`], along with the full record
+/// of the check.
+pub async fn get_carrier_status_by_phone_number_detailed(
+    api_key: &str,
+    phone_number: impl TryInto<Msisdn>,
+) -> Result<CheckRecord<CarrierStatus>> {
+    let Ok(phone_number) = phone_number.try_into() else {
+        return Err(ServiceCheckError::InvalidPhoneNumber);
+    };
+
+    let record = check_service_detailed::<ApiCarrierLookupStatus>(api_key, &phone_number).await?;
+    Ok(record
+        .map(|status| CarrierStatus::from_api(phone_number.into(), CarrierCheck::Lookup, status.0)))
+}
+
+/// Check an identifier with the service associated with the result type `T`, and return the result as-is.
+/// The identifier is passed to the service unchanged, so it is up to the caller to make sure that it is a kind
+/// of identifier the service accepts.
+///
+/// This method will return an error in the same cases as the other lookups, and also if the result does not
+/// match the shape of `T`.
+pub async fn check_service<T: ServiceCheck>(api_key: &str, identifier: &impl Display) -> Result<T> {
+    Ok(check_service_detailed(api_key, identifier).await?.result)
+}
+
+/// Check an identifier with the service associated with the result type `T`, as with [`check_service`], along
+/// with the full record of the check.
+pub async fn check_service_detailed<T: ServiceCheck>(
+    api_key: &str,
+    identifier: &impl Display,
+) -> Result<CheckRecord<T>> {
+    let response = crate::api::check_with_service::<T>(T::SERVICE, api_key, identifier).await?;
+    Ok(response.into())
+}
+
+/// Check an identifier with any service, including ones the crate does not model yet, and return the
+/// result as untyped JSON.
+///
+/// This method will return an error in the same cases as [`check_service`], except that any result is accepted.
+pub async fn check_service_raw(
+    api_key: &str,
+    service: Service,
+    identifier: &impl Display,
+) -> Result<serde_json::Value> {
+    Ok(check_service_raw_detailed(api_key, service, identifier)
+        .await?
+        .result)
+}
+
+/// Check an identifier with any service, as with [`check_service_raw`], along with the full record of the check.
+pub async fn check_service_raw_detailed(
+    api_key: &str,
+    service: Service,
+    identifier: &impl Display,
+) -> Result<CheckRecord<serde_json::Value>> {
     let response =
-        crate::api::check_imei_with_service(BASIC_IMEI_CHECK_SID, api_key, &Imei::from(tac))
-            .await?;
-    Ok(response.result.into())
+        crate::api::check_with_service::<serde_json::Value>(service, api_key, identifier).await?;
+    Ok(response.into())
+}
+
+async fn check_phone_info(api_key: &str, imei: &Imei) -> Result<CheckRecord<PhoneInfo>> {
+    let record = check_service_raw_detailed(api_key, Service::BasicImeiCheck, imei).await?;
+    let body = record.result.clone();
+    let imei2 = record.imei2.clone();
+    record
+        .try_map(|body| ApiPhoneInfo::deserialize(&body).map_err(|error| error.to_string()))
+        .and_then(|record| {
+            record.try_map(|info| {
+                PhoneInfo::from_api(info, imei2.as_deref())
+                    .map_err(|error| format!("IMEI in result is invalid: {error}"))
+            })
+        })
+        .map_err(|detail| ServiceCheckError::MalformedResult { detail, body })
 }
@@ -0,0 +1,185 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::sim::FromStrVisitor;
+#[cfg(feature = "client")]
+use crate::api::ApiMacAddressInfo;
+use crate::wrapper::ImeiWrapperError;
+
+/// Vendors of common OUIs, mostly for phones, tablets, and other consumer devices.
+#[cfg(feature = "oui")]
+const OUI_VENDORS: &[([u8; 3], &str)] = &[
+    ([0x00, 0x03, 0x93], "Apple"),
+    ([0x00, 0x0A, 0x95], "Apple"),
+    ([0x00, 0x12, 0xFB], "Samsung"),
+    ([0x00, 0x15, 0x5D], "Microsoft"),
+    ([0x00, 0x16, 0x32], "Samsung"),
+    ([0x00, 0x18, 0x82], "Huawei"),
+    ([0x00, 0x1E, 0xC2], "Apple"),
+    ([0x00, 0x25, 0x00], "Apple"),
+    ([0x00, 0x50, 0xF2], "Microsoft"),
+    ([0x00, 0xE0, 0xFC], "Huawei"),
+    ([0x28, 0x6C, 0x07], "Xiaomi"),
+    ([0x28, 0xCF, 0xE9], "Apple"),
+    ([0x3C, 0x07, 0x54], "Apple"),
+    ([0x3C, 0x5A, 0xB4], "Google"),
+    ([0x44, 0x65, 0x0D], "Amazon"),
+    ([0x64, 0x09, 0x80], "Xiaomi"),
+    ([0x74, 0xC2, 0x46], "Amazon"),
+    ([0xAC, 0xBC, 0x32], "Apple"),
+    ([0xB8, 0x27, 0xEB], "Raspberry Pi"),
+    ([0xDC, 0xA6, 0x32], "Raspberry Pi"),
+    ([0xF0, 0x18, 0x98], "Apple"),
+    ([0xF4, 0xF5, 0xD8], "Google"),
+    ([0xF8, 0xA4, 0x5F], "Xiaomi"),
+    ([0xFC, 0x65, 0xDE], "Amazon"),
+];
+
+/// A MAC address (EUI-48), which identifies the network interface of a device.
+///
+/// It can be parsed from colon-separated (`3c:07:54:12:34:56`), dash-separated (`3C-07-54-12-34-56`),
+/// dot-separated (`3c07.5412.3456`), or bare (`3c0754123456`) hexadecimal, and it is displayed and
+/// serialized in uppercase with colons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MacAddress {
+    pub octets: [u8; 6],
+}
+
+/// The information about a device's network interface returned by the MAC address check.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacAddressInfo {
+    pub mac_address: MacAddress,
+    pub vendor: Option<String>,
+    /// The registered address of the vendor.
+    pub address: Option<String>,
+    pub country: Option<String>,
+    pub model: Option<String>,
+    pub device_type: Option<String>,
+}
+
+#[cfg(feature = "client")]
+impl MacAddressInfo {
+    pub(crate) fn from_api(mac_address: MacAddress, info: ApiMacAddressInfo) -> Self {
+        Self {
+            mac_address,
+            vendor: Some(info.vendor).filter(|vendor| !vendor.trim().is_empty()),
+            address: info.address,
+            country: info.country,
+            model: info.model,
+            device_type: info.device_type,
+        }
+    }
+}
+
+impl MacAddress {
+    /// Retrieve the organizationally unique identifier (OUI), which identifies the vendor (the first three octets).
+    pub fn oui(&self) -> &[u8; 3] {
+        self.octets[0..3].try_into().unwrap()
+    }
+
+    /// Retrieve the part of the address assigned by the vendor (the last three octets).
+    pub fn network_interface_controller(&self) -> &[u8; 3] {
+        self.octets[3..6].try_into().unwrap()
+    }
+
+    /// Check if the address is locally administered rather than assigned by the vendor. Randomized addresses,
+    /// which phones use by default when scanning for or joining Wi-Fi networks, are always locally administered,
+    /// so their OUI does not identify the vendor.
+    pub fn is_locally_administered(&self) -> bool {
+        self.octets[0] & 0b10 != 0
+    }
+
+    /// Check if the address is a multicast (group) address rather than the address of a single interface.
+    pub fn is_multicast(&self) -> bool {
+        self.octets[0] & 0b01 != 0
+    }
+
+    /// Look up the vendor of the OUI in the embedded table. Returns `None` for locally administered addresses.
+    #[cfg(feature = "oui")]
+    pub fn vendor(&self) -> Option<&'static str> {
+        if self.is_locally_administered() {
+            return None;
+        }
+
+        OUI_VENDORS
+            .iter()
+            .find(|(oui, _)| oui == self.oui())
+            .map(|(_, vendor)| *vendor)
+    }
+}
+
+impl FromStr for MacAddress {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        // * Each format has its separators at fixed positions, so they are checked before the digits
+        let (separator, separator_positions): (u8, &[usize]) = match bytes.len() {
+            12 => (0, &[]),
+            14 if bytes[4] == b'.' => (b'.', &[4, 9]),
+            17 if bytes[2] == b'-' => (b'-', &[2, 5, 8, 11, 14]),
+            17 => (b':', &[2, 5, 8, 11, 14]),
+            _ => return Err(ImeiWrapperError::IncorrectLength),
+        };
+
+        let mut digits = bytes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !separator_positions.contains(i));
+        if separator_positions.iter().any(|i| bytes[*i] != separator) {
+            return Err(ImeiWrapperError::CannotParseDigits);
+        }
+
+        let mut octets = [0u8; 6];
+        for octet in octets.iter_mut() {
+            let (_, high) = digits.next().unwrap();
+            let (_, low) = digits.next().unwrap();
+            *octet = (hex_value(*high)? << 4) | hex_value(*low)?;
+        }
+
+        Ok(Self { octets })
+    }
+}
+
+impl TryFrom<&str> for MacAddress {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    fn from(octets: [u8; 6]) -> Self {
+        Self { octets }
+    }
+}
+
+impl Display for MacAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [a, b, c, d, e, g] = self.octets;
+        write!(f, "{a:02X}:{b:02X}:{c:02X}:{d:02X}:{e:02X}:{g:02X}")
+    }
+}
+
+impl Serialize for MacAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MacAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a MAC address"))
+    }
+}
+
+fn hex_value(c: u8) -> Result<u8, ImeiWrapperError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ImeiWrapperError::CannotParseDigits),
+    }
+}
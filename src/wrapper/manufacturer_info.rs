@@ -0,0 +1,145 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{parse_api_date, ApiGoogleInfo, ApiLgInfo, ApiManufacturerInfo, ApiSonyInfo};
+use crate::wrapper::{Imei, SamsungInfo, XiaomiInfo};
+use crate::Brand;
+
+/// The information about a device returned by the info check of its manufacturer, in a shape which is shared
+/// between brands. The fields which only some brands report are in [`ManufacturerInfo::details`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManufacturerInfo {
+    pub imei: Imei,
+    pub brand: Brand,
+    /// The marketing name of the model, such as `"Pixel 7"`.
+    pub model: Option<String>,
+    /// The exact SKU or model number of the device, such as `"GVU6C"` or `"XQ-AT51"`.
+    pub sku: Option<String>,
+    /// The variant of the model, such as its storage size or color, if the service reports it as a whole.
+    pub variant: Option<String>,
+    /// The region the device was made for, such as `"EU"` or a carrier name.
+    pub region: Option<String>,
+    pub sold_country: Option<String>,
+    pub manufacture_date: Option<NaiveDate>,
+    pub warranty_end: Option<NaiveDate>,
+    pub details: ManufacturerDetails,
+}
+
+/// The information which is specific to the info check of one brand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ManufacturerDetails {
+    Google {
+        color: Option<String>,
+        storage: Option<String>,
+    },
+    Lg {
+        /// The buyer code (suffix) of the model, which identifies the carrier or market it was made for, such as `"AUSAWH"`.
+        buyer_code: Option<String>,
+        carrier: Option<String>,
+    },
+    Sony {
+        color: Option<String>,
+        /// The customization (CDA) code, which identifies the carrier or market the firmware was made for.
+        customization: Option<String>,
+    },
+    Samsung {
+        product_code: Option<String>,
+        csc: Option<String>,
+    },
+    Xiaomi {
+        activated: Option<bool>,
+        activation_date: Option<NaiveDate>,
+    },
+}
+
+impl ManufacturerInfo {
+    fn from_api_parts(
+        imei: Imei,
+        brand: Brand,
+        info: ApiManufacturerInfo,
+        details: ManufacturerDetails,
+    ) -> Self {
+        Self {
+            imei,
+            brand,
+            model: info.model,
+            sku: info.sku,
+            variant: info.variant,
+            region: info.region,
+            sold_country: info.sold_country,
+            manufacture_date: parse_api_date(info.manufacture_date.as_deref()),
+            warranty_end: parse_api_date(info.warranty_end.as_deref()),
+            details,
+        }
+    }
+
+    pub(crate) fn from_api_google(imei: Imei, info: ApiGoogleInfo) -> Self {
+        let details = ManufacturerDetails::Google {
+            color: info.color,
+            storage: info.storage,
+        };
+        Self::from_api_parts(imei, Brand::Google, info.info, details)
+    }
+
+    pub(crate) fn from_api_lg(imei: Imei, info: ApiLgInfo) -> Self {
+        let details = ManufacturerDetails::Lg {
+            buyer_code: info.buyer_code,
+            carrier: info.carrier,
+        };
+        Self::from_api_parts(imei, Brand::Lg, info.info, details)
+    }
+
+    pub(crate) fn from_api_sony(imei: Imei, info: ApiSonyInfo) -> Self {
+        let details = ManufacturerDetails::Sony {
+            color: info.color,
+            customization: info.customization,
+        };
+        Self::from_api_parts(imei, Brand::Sony, info.info, details)
+    }
+
+    /// Check whether the device is covered at the given time, including the last day of coverage. Devices
+    /// without a known end of coverage are not considered covered.
+    pub fn is_in_warranty(&self, at: DateTime<Utc>) -> bool {
+        self.warranty_end.is_some_and(|end| at.date_naive() <= end)
+    }
+}
+
+impl From<SamsungInfo> for ManufacturerInfo {
+    fn from(info: SamsungInfo) -> Self {
+        Self {
+            imei: info.imei,
+            brand: Brand::Samsung,
+            model: info.model_name,
+            sku: info.model_code,
+            variant: None,
+            region: info.csc.clone(),
+            sold_country: info.sold_country,
+            manufacture_date: info.manufacture_date,
+            warranty_end: info.warranty_end,
+            details: ManufacturerDetails::Samsung {
+                product_code: info.product_code,
+                csc: info.csc,
+            },
+        }
+    }
+}
+
+impl From<XiaomiInfo> for ManufacturerInfo {
+    fn from(info: XiaomiInfo) -> Self {
+        Self {
+            imei: info.imei,
+            brand: Brand::Xiaomi,
+            model: info.model,
+            sku: info.model_code,
+            variant: None,
+            region: info.region,
+            sold_country: info.sold_country,
+            manufacture_date: info.manufacture_date,
+            warranty_end: info.warranty_end,
+            details: ManufacturerDetails::Xiaomi {
+                activated: info.activated,
+                activation_date: info.activation_date,
+            },
+        }
+    }
+}
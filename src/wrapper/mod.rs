@@ -1,5 +1,43 @@
+mod apple;
+#[cfg(feature = "client")]
+mod apple_info;
+#[cfg(feature = "client")]
+mod blacklist;
+#[cfg(feature = "client")]
+mod carrier_status;
+#[cfg(feature = "client")]
 mod logic;
+mod mac;
+#[cfg(feature = "client")]
+mod manufacturer_info;
 mod model;
+#[cfg(feature = "client")]
+mod record;
+#[cfg(feature = "client")]
+mod samsung_info;
+mod sim;
+mod subscriber;
+#[cfg(feature = "client")]
+mod xiaomi_info;
 
+pub use apple::*;
+#[cfg(feature = "client")]
+pub use apple_info::*;
+#[cfg(feature = "client")]
+pub use blacklist::*;
+#[cfg(feature = "client")]
+pub use carrier_status::*;
+#[cfg(feature = "client")]
 pub use logic::*;
+pub use mac::*;
+#[cfg(feature = "client")]
+pub use manufacturer_info::*;
 pub use model::*;
+#[cfg(feature = "client")]
+pub use record::*;
+#[cfg(feature = "client")]
+pub use samsung_info::*;
+pub use sim::*;
+pub use subscriber::*;
+#[cfg(feature = "client")]
+pub use xiaomi_info::*;
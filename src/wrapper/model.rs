@@ -1,10 +1,15 @@
-use std::error::Error;
-use std::fmt::Display;
-use std::str::FromStr;
+use core::error::Error;
+use core::fmt::Display;
+use core::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "client")]
 use crate::api::ApiPhoneInfo;
+use crate::wrapper::sim::FromStrVisitor;
+use crate::wrapper::AppleSerial;
+#[cfg(feature = "client")]
+use crate::DeviceImeis;
 
 /// An IMEI number, represented using an array of digits to prevent integer over/underflow or
 /// leading-zero truncation.
@@ -12,7 +17,8 @@ use crate::api::ApiPhoneInfo;
 /// This type implements `Into` for `i64`, `u64`, `i128`, and `u128`. It does not implement `Into`
 /// for 32-bit or platform-dependent integer types because the IMEI is 15 digits and typically
 /// cannot be stored in anything less than a 64-bit integer.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Imei {
     pub digits: [u8; 15],
 }
@@ -21,34 +27,64 @@ pub struct Imei {
 /// leading-zero truncation.
 ///
 /// This type implements `Into` for `i32`, `u32`, `i64`, `u64`, `i128`, `u128`, `isize`, and `usize`.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Tac {
     pub digits: [u8; 8],
 }
 
-/// The basic information about a phone: its IMEI, make, and model.
+/// An IMEISV number (IMEI with software version), represented using an array of digits to prevent
+/// integer over/underflow or leading-zero truncation.
+///
+/// The IMEISV replaces the IMEI check digit with a two-digit software version number (SVN), so it
+/// has no checksum of its own.
+///
+/// This type implements `Into` for `i64`, `u64`, `i128`, and `u128`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(C)]
+pub struct ImeiSv {
+    pub digits: [u8; 16],
+}
+
+/// An identifier for a device which is accepted by services that look up either an IMEI or a serial number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceIdentifier {
+    Imei(Imei),
+    AppleSerial(AppleSerial),
+}
+
+/// The basic information about a phone: its IMEIs, make, and model.
 /// This is generally used in a context where the IMEI is already known, but it is included for
-/// flexibility's sake.
+/// flexibility's sake, and because the API also returns the second IMEI of dual-SIM devices.
+#[cfg(feature = "client")]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhoneInfo {
-    pub imei: Imei,
+    pub imeis: DeviceImeis,
     pub manufacturer: String,
     pub model: String,
 }
 
-impl From<ApiPhoneInfo> for PhoneInfo {
-    fn from(info: ApiPhoneInfo) -> Self {
-        Self {
-            imei: Imei::from_str(&info.imei).unwrap(),
+#[cfg(feature = "client")]
+impl PhoneInfo {
+    pub(crate) fn from_api(
+        info: ApiPhoneInfo,
+        imei2: Option<&str>,
+    ) -> Result<Self, ImeiWrapperError> {
+        let primary = Imei::from_str(&info.imei)?;
+        // * A second IMEI which cannot be parsed is dropped rather than failing the whole lookup
+        let secondary = imei2.and_then(|imei2| Imei::from_str(imei2).ok());
+        Ok(Self {
+            imeis: DeviceImeis::new(primary, secondary),
             manufacturer: info.brand_name,
             model: info.model,
-        }
+        })
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImeiWrapperError {
     ValueOutOfRange,
+    IncorrectLength,
     CannotParseDigits,
     ChecksumDoesNotMatch,
 }
@@ -56,16 +92,19 @@ pub enum ImeiWrapperError {
 impl Error for ImeiWrapperError {}
 
 impl Display for ImeiWrapperError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(match self {
             ImeiWrapperError::ValueOutOfRange => {
                 "provided numeric value is out of the required range for an IMEI or TAC"
             }
+            ImeiWrapperError::IncorrectLength => {
+                "the string does not contain the required number of digits for the identifier"
+            }
             ImeiWrapperError::CannotParseDigits => {
-                "one or more characters in the string is not numeric"
+                "one or more characters in the string is not valid for the identifier"
             }
             ImeiWrapperError::ChecksumDoesNotMatch => {
-                "the IMEI check digit does not match its Luhn checksum"
+                "the check digit does not match the checksum of the identifier"
             }
         })
     }
@@ -78,6 +117,10 @@ impl Imei {
     }
 
     /// Retrieve the bare model identifier, excluding the reporting body code (digits 3 through 8 of the IMEI).
+    ///
+    /// This only applies to IMEIs allocated since 2004. Before then, only digits 3 through 6 identified the model
+    /// (as part of a six-digit type approval code), and digits 7 and 8 were a final assembly code identifying
+    /// the factory; see [`Imei::explain`].
     pub fn model_identifier(&self) -> &[u8; 6] {
         self.digits[2..8].try_into().unwrap()
     }
@@ -106,6 +149,65 @@ impl Imei {
     pub fn is_valid(&self) -> bool {
         luhn_checksum(self.without_check_digit()) == self.check_digit()
     }
+
+    /// Parse an IMEI which may contain separators, such as `35-674108-972868-6` or `35 674108 972868 6`.
+    /// Whitespace, dashes, dots, and slashes are ignored; any other non-numeric character is rejected.
+    pub fn parse_lenient(s: &str) -> Result<Self, ImeiWrapperError> {
+        let imei = Self {
+            digits: lenient_string_to_digits(s)?,
+        };
+        if !imei.is_valid() {
+            return Err(ImeiWrapperError::ChecksumDoesNotMatch);
+        }
+
+        Ok(imei)
+    }
+}
+
+impl ImeiSv {
+    /// Retrieve the reporting body code (the first two digits of the IMEISV).
+    pub fn reporting_body(&self) -> &[u8; 2] {
+        self.digits[0..2].try_into().unwrap()
+    }
+
+    /// Retrieve the type allocation code (TAC), which is the reporting body code and model identifier (digits 1 through 8 of the IMEISV).
+    pub fn type_allocation_code(&self) -> &[u8; 8] {
+        self.digits[0..8].try_into().unwrap()
+    }
+
+    /// Retrieve the unit serial number (digits 9 through 14 of the IMEISV).
+    pub fn serial_number(&self) -> &[u8; 6] {
+        self.digits[8..14].try_into().unwrap()
+    }
+
+    /// Retrieve the software version number (SVN), which takes the place of the IMEI check digit (digits 15 and 16 of the IMEISV).
+    pub fn software_version_number(&self) -> &[u8; 2] {
+        self.digits[14..16].try_into().unwrap()
+    }
+
+    /// Retrieve the IMEI of the device, which is the IMEISV with the SVN replaced by a computed check digit.
+    pub fn imei(&self) -> Imei {
+        let mut imei_digits = [0u8; 15];
+        imei_digits[..14].copy_from_slice(&self.digits[..14]);
+        imei_digits[14] = luhn_checksum(&imei_digits[..14]);
+
+        Imei {
+            digits: imei_digits,
+        }
+    }
+
+    /// Parse an IMEISV which may contain separators, such as `35-674108-972868-01`.
+    /// Whitespace, dashes, dots, and slashes are ignored; any other non-numeric character is rejected.
+    pub fn parse_lenient(s: &str) -> Result<Self, ImeiWrapperError> {
+        Ok(Self {
+            digits: lenient_string_to_digits(s)?,
+        })
+    }
+
+    // * This function only exists to make the `impl_int_to_digits` macro work for `ImeiSv`
+    const fn is_valid(&self) -> bool {
+        true
+    }
 }
 
 impl Tac {
@@ -115,10 +217,19 @@ impl Tac {
     }
 
     /// Retrieve the bare model identifier, excluding the reporting body code (digits 3 through 8 of the TAC).
+    /// As with [`Imei::model_identifier`], digits 7 and 8 were a final assembly code before 2004.
     pub fn model_identifier(&self) -> &[u8; 6] {
         self.digits[2..=7].try_into().unwrap()
     }
 
+    /// Parse a TAC which may contain separators, such as `35-674108`.
+    /// Whitespace, dashes, dots, and slashes are ignored; any other non-numeric character is rejected.
+    pub fn parse_lenient(s: &str) -> Result<Self, ImeiWrapperError> {
+        Ok(Self {
+            digits: lenient_string_to_digits(s)?,
+        })
+    }
+
     // * This function only exists to make the `impl_int_to_digits` macro work for `Tac`
     const fn is_valid(&self) -> bool {
         true
@@ -197,20 +308,21 @@ macro_rules! impl_digits_to_int {
 
 impl_int_to_digits!(i32, u32, i64, u64, i128, u128, isize, usize; Imei; 15);
 impl_int_to_digits!(i32, u32, i64, u64, i128, u128, isize, usize; Tac; 8);
+impl_int_to_digits!(i32, u32, i64, u64, i128, u128, isize, usize; ImeiSv; 16);
 
 impl_digits_to_int!(i32, u32, i64, u64, i128, u128, isize, usize; Tac);
 impl_digits_to_int!(i32, u32, i64, u64, i128, u128, isize, usize; &Tac);
 impl_digits_to_int!(i64, u64, i128, u128; Imei);
 impl_digits_to_int!(i64, u64, i128, u128; &Imei);
+impl_digits_to_int!(i64, u64, i128, u128; ImeiSv);
+impl_digits_to_int!(i64, u64, i128, u128; &ImeiSv);
 
 impl FromStr for Imei {
     type Err = ImeiWrapperError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some(digits) = string_to_digits(s) else {
-            return Err(ImeiWrapperError::CannotParseDigits);
+        let imei = Self {
+            digits: string_to_digits(s)?,
         };
-
-        let imei = Self { digits };
         if !imei.is_valid() {
             return Err(ImeiWrapperError::ChecksumDoesNotMatch);
         }
@@ -222,9 +334,28 @@ impl FromStr for Imei {
 impl FromStr for Tac {
     type Err = ImeiWrapperError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match string_to_digits(s) {
-            Some(digits) => Ok(Self { digits }),
-            None => Err(ImeiWrapperError::CannotParseDigits),
+        Ok(Self {
+            digits: string_to_digits(s)?,
+        })
+    }
+}
+
+impl FromStr for ImeiSv {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            digits: string_to_digits(s)?,
+        })
+    }
+}
+
+impl FromStr for DeviceIdentifier {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 15 && s.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(DeviceIdentifier::Imei(Imei::from_str(s)?))
+        } else {
+            Ok(DeviceIdentifier::AppleSerial(AppleSerial::from_str(s)?))
         }
     }
 }
@@ -243,36 +374,116 @@ impl TryFrom<&str> for Tac {
     }
 }
 
-impl ToString for Imei {
-    fn to_string(&self) -> String {
-        self.digits.iter().map(|d| d.to_string()).collect()
+impl TryFrom<&str> for ImeiSv {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&str> for DeviceIdentifier {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl From<Imei> for DeviceIdentifier {
+    fn from(imei: Imei) -> Self {
+        DeviceIdentifier::Imei(imei)
+    }
+}
+
+impl From<AppleSerial> for DeviceIdentifier {
+    fn from(serial: AppleSerial) -> Self {
+        DeviceIdentifier::AppleSerial(serial)
     }
 }
 
-impl ToString for Tac {
-    fn to_string(&self) -> String {
-        self.digits.iter().map(|d| d.to_string()).collect()
+impl Display for DeviceIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeviceIdentifier::Imei(imei) => imei.fmt(f),
+            DeviceIdentifier::AppleSerial(serial) => serial.fmt(f),
+        }
+    }
+}
+
+impl Serialize for DeviceIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeviceIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an IMEI or an Apple serial number"))
     }
 }
 
-fn string_to_digits<const N: usize>(s: &str) -> Option<[u8; N]> {
+impl Display for Imei {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits.iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Display for Tac {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits.iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Display for ImeiSv {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits.iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+pub(crate) fn string_to_digits<const N: usize>(s: &str) -> Result<[u8; N], ImeiWrapperError> {
+    chars_to_digits(s.chars())
+}
+
+fn lenient_string_to_digits<const N: usize>(s: &str) -> Result<[u8; N], ImeiWrapperError> {
+    chars_to_digits(
+        s.chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '/')),
+    )
+}
+
+fn chars_to_digits<const N: usize>(
+    chars: impl Iterator<Item = char>,
+) -> Result<[u8; N], ImeiWrapperError> {
     let mut digits = [0u8; N];
-    for (i, c) in s.chars().enumerate() {
-        if let Some(digit) = c.to_digit(10) {
-            digits[i] = digit as u8;
-        } else {
-            return None;
+    let mut count = 0;
+    for c in chars {
+        let Some(digit) = c.to_digit(10) else {
+            return Err(ImeiWrapperError::CannotParseDigits);
+        };
+
+        if count == N {
+            return Err(ImeiWrapperError::IncorrectLength);
         }
+
+        digits[count] = digit as u8;
+        count += 1;
+    }
+
+    if count != N {
+        return Err(ImeiWrapperError::IncorrectLength);
     }
 
-    Some(digits)
+    Ok(digits)
 }
 
-pub(crate) fn luhn_checksum(digits: &[u8]) -> u8 {
+/// Compute the Luhn check digit for a sequence of digits, such as an IMEI without its check digit.
+/// Every second digit is doubled starting from the rightmost one, so the result is correct for inputs of
+/// any length, including odd-length ones such as the first 19 digits of a 20-digit ICCID.
+pub fn luhn_checksum(digits: &[u8]) -> u8 {
     let mut checksum = 0;
-    for (i, digit) in digits.into_iter().enumerate() {
+    for (i, digit) in digits.iter().enumerate() {
         let digit = *digit as u32;
-        if i % 2 != 0 {
+        // * Every second digit is doubled, starting from the rightmost one
+        if (digits.len() - i) % 2 == 1 {
             let double_digit = digit * 2;
             if double_digit < 10 {
                 checksum += double_digit;
@@ -0,0 +1,129 @@
+use std::convert::Infallible;
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::api::ServiceCheckStandardResponseBody;
+use crate::Service;
+
+/// The status of a check, as reported by the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Done,
+    #[serde(rename = "In_progress")]
+    InProgress,
+    Completed,
+    Rejected,
+}
+
+/// The full record of a check, including the metadata which the API returns alongside the result.
+/// This is returned by the `_detailed` variants of the lookups, for billing reconciliation and audits.
+///
+/// The `Debug` implementation redacts the token key.
+#[derive(Clone, PartialEq)]
+pub struct CheckRecord<T> {
+    /// The ID of the check in the history of the account.
+    pub id: u32,
+    /// The ULID of the check, which also encodes when it was created.
+    pub ulid: Option<Ulid>,
+    pub status: CheckStatus,
+    pub service: Service,
+    /// The name of the service, as reported by the API.
+    pub service_name: String,
+    pub created_at: DateTime<Utc>,
+    pub requested_at: DateTime<Utc>,
+    /// The number of tokens the check cost.
+    pub token_request_price: Decimal,
+    pub token_key: String,
+    /// The identifiers which were checked, as reported by the API. Which of these are present depends on the service.
+    pub imei: Option<String>,
+    pub imei2: Option<String>,
+    pub sn: Option<String>,
+    pub phone_number: Option<String>,
+    /// A free-text summary of the result, which some services return in addition to (or instead of) structured data.
+    pub text: Option<String>,
+    pub result: T,
+}
+
+impl<T> CheckRecord<T> {
+    /// Retrieve the time embedded in the ULID of the check, with millisecond precision.
+    pub fn ulid_timestamp(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(self.ulid?.timestamp_ms() as i64)
+    }
+
+    /// Convert the result of the check, keeping the rest of the record.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> CheckRecord<U> {
+        match self.try_map(|result| Ok::<_, Infallible>(f(result))) {
+            Ok(record) => record,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Convert the result of the check with a conversion which can fail, keeping the rest of the record.
+    pub fn try_map<U, E>(self, f: impl FnOnce(T) -> Result<U, E>) -> Result<CheckRecord<U>, E> {
+        Ok(CheckRecord {
+            id: self.id,
+            ulid: self.ulid,
+            status: self.status,
+            service: self.service,
+            service_name: self.service_name,
+            created_at: self.created_at,
+            requested_at: self.requested_at,
+            token_request_price: self.token_request_price,
+            token_key: self.token_key,
+            imei: self.imei,
+            imei2: self.imei2,
+            sn: self.sn,
+            phone_number: self.phone_number,
+            text: self.text,
+            result: f(self.result)?,
+        })
+    }
+}
+
+impl<T> From<ServiceCheckStandardResponseBody<T>> for CheckRecord<T> {
+    fn from(response: ServiceCheckStandardResponseBody<T>) -> Self {
+        Self {
+            id: response.id,
+            ulid: response.ulid,
+            status: response.status,
+            service: Service::from_id(response.service_id),
+            service_name: response.service,
+            created_at: response.created_at,
+            requested_at: response.requested_at,
+            token_request_price: response.token_request_price,
+            token_key: response.token_key,
+            imei: response.imei,
+            imei2: response.imei2,
+            sn: response.sn,
+            phone_number: response.phone_number,
+            text: response.text,
+            result: response.result,
+        }
+    }
+}
+
+impl<T: Debug> Debug for CheckRecord<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckRecord")
+            .field("id", &self.id)
+            .field("ulid", &self.ulid)
+            .field("status", &self.status)
+            .field("service", &self.service)
+            .field("service_name", &self.service_name)
+            .field("created_at", &self.created_at)
+            .field("requested_at", &self.requested_at)
+            .field("token_request_price", &self.token_request_price)
+            .field("token_key", &"<redacted>")
+            .field("imei", &self.imei)
+            .field("imei2", &self.imei2)
+            .field("sn", &self.sn)
+            .field("phone_number", &self.phone_number)
+            .field("text", &self.text)
+            .field("result", &self.result)
+            .finish()
+    }
+}
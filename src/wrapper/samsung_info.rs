@@ -0,0 +1,134 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{parse_api_date, ApiFlag, ApiSamsungInfo, ApiSamsungKnoxInfo};
+use crate::wrapper::{Imei, PhoneInfo};
+
+/// Words in model names which describe the brand or chipset rather than the model, such as in `"Galaxy S10 Exynos"`.
+const IGNORED_MODEL_WORDS: &[&str] = &["samsung", "exynos", "snapdragon", "duos"];
+
+/// The information about a Samsung device returned by the Samsung info check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamsungInfo {
+    pub imei: Imei,
+    /// The model code, such as `"SM-G973F"`.
+    pub model_code: Option<String>,
+    /// The marketing name of the model, such as `"Galaxy S10"`.
+    pub model_name: Option<String>,
+    /// The full product code, which is the model code followed by the color and the CSC, such as `"SM-G973FZKDXEU"`.
+    pub product_code: Option<String>,
+    /// The customer-specific code (CSC), which identifies the region or carrier the device was made for, such as `"XEU"`.
+    pub csc: Option<String>,
+    pub sold_country: Option<String>,
+    pub manufacture_date: Option<NaiveDate>,
+    pub warranty_end: Option<NaiveDate>,
+}
+
+/// The Knox status of a Samsung device returned by the Samsung Knox info check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SamsungKnoxInfo {
+    pub imei: Imei,
+    /// Whether Knox Guard is active, which lets a carrier or financing provider lock the device remotely.
+    pub knox_guard: Option<bool>,
+    /// Whether the device is enrolled in Knox Mobile Enrollment, which ties it to an organization's device management.
+    pub knox_enrollment: Option<bool>,
+    pub enrolled_organization: Option<String>,
+    /// Whether the Knox warranty bit has been tripped by flashing unofficial software, which voids the warranty.
+    pub warranty_void: Option<bool>,
+}
+
+/// The result of comparing the model a service reports for a device with the model of its TAC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModelMatch {
+    Match,
+    /// The models differ, which usually means that the board (and so the IMEI) comes from another device.
+    Mismatch,
+    /// There is not enough information to compare the models.
+    Unknown,
+}
+
+impl SamsungInfo {
+    pub(crate) fn from_api(imei: Imei, info: ApiSamsungInfo) -> Self {
+        Self {
+            imei,
+            model_code: info.model,
+            model_name: info.model_name,
+            product_code: info.product_code,
+            csc: info.csc,
+            sold_country: info.sold_country,
+            manufacture_date: parse_api_date(info.manufacture_date.as_deref()),
+            warranty_end: parse_api_date(info.warranty_end.as_deref()),
+        }
+    }
+
+    /// Check whether the device is covered at the given time, including the last day of coverage. Devices
+    /// without a known end of coverage are not considered covered.
+    pub fn is_in_warranty(&self, at: DateTime<Utc>) -> bool {
+        self.warranty_end.is_some_and(|end| at.date_naive() <= end)
+    }
+
+    /// Compare the model of the device with the model of its TAC, as returned by [`get_tac_info`](crate::get_tac_info).
+    /// The TAC is assigned when the device is made, so a mismatch usually means that the board has been swapped.
+    ///
+    /// The models match if the TAC model contains the model code without its regional suffix (so `SM-G973F` matches
+    /// `SM-G973U`), or if the marketing names are the same, ignoring case, punctuation, and chipset names.
+    pub fn check_model(&self, tac_info: &PhoneInfo) -> ModelMatch {
+        let tac_model = normalize(&tac_info.model);
+        if tac_model.is_empty() {
+            return ModelMatch::Unknown;
+        }
+
+        let base_model_code = self.model_code.as_deref().map(|model_code| {
+            let model_code = normalize(model_code);
+            let digits_end = model_code
+                .rfind(|c: char| c.is_ascii_digit())
+                .map_or(model_code.len(), |i| i + 1);
+            model_code[..digits_end].to_owned()
+        });
+        if base_model_code.is_some_and(|code| !code.is_empty() && tac_model.contains(&code)) {
+            return ModelMatch::Match;
+        }
+
+        match self.model_name.as_deref().map(normalize) {
+            Some(name) if !name.is_empty() => {
+                if name == tac_model {
+                    ModelMatch::Match
+                } else {
+                    ModelMatch::Mismatch
+                }
+            }
+            _ => ModelMatch::Unknown,
+        }
+    }
+}
+
+impl SamsungKnoxInfo {
+    pub(crate) fn from_api(imei: Imei, info: ApiSamsungKnoxInfo) -> Self {
+        Self {
+            imei,
+            knox_guard: info.knox_guard.as_ref().and_then(ApiFlag::value),
+            knox_enrollment: info.knox_enrollment.as_ref().and_then(ApiFlag::value),
+            enrolled_organization: info.enrolled_organization,
+            warranty_void: info.warranty_void.as_ref().and_then(ApiFlag::value),
+        }
+    }
+}
+
+/// Lowercase a model name and remove everything which is not alphanumeric, along with the words which do not
+/// identify the model. A `+` is kept as `plus`, since it distinguishes models like the Galaxy S10 and S10+.
+fn normalize(model: &str) -> String {
+    model
+        .split_whitespace()
+        .filter(|word| {
+            !IGNORED_MODEL_WORDS
+                .iter()
+                .any(|ignored| word.eq_ignore_ascii_case(ignored))
+        })
+        .flat_map(str::chars)
+        .flat_map(|c| match c {
+            '+' => "plus".chars().collect(),
+            c if c.is_alphanumeric() => c.to_lowercase().collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
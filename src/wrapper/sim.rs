@@ -0,0 +1,316 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::wrapper::{luhn_checksum, string_to_digits, ImeiWrapperError};
+
+/// An ICCID (integrated circuit card identifier), the serial number printed on a SIM card or assigned to
+/// an eSIM profile, represented using an array of digits to prevent leading-zero truncation.
+///
+/// An ICCID is 19 or 20 digits long, following ITU-T E.118: the `89` major industry identifier, an E.164
+/// country code, an issuer identifier, an account number, and a Luhn check digit.
+/// It is serialized as a string of digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iccid {
+    digits: [u8; 20],
+    length: usize,
+}
+
+/// An EID (eUICC identifier), the 32-digit number identifying the embedded chip which holds eSIM profiles,
+/// represented using an array of digits to prevent leading-zero truncation.
+///
+/// The layout follows GSMA SGP.29, and the last two digits are check digits computed using ISO 7064 MOD 97-10.
+/// It is serialized as a string of digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Eid {
+    pub digits: [u8; 32],
+}
+
+/// An identifier accepted by the eSIM information check, either the ICCID of a profile or the EID of a device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EsimIdentifier {
+    Iccid(Iccid),
+    Eid(Eid),
+}
+
+impl Iccid {
+    /// Retrieve all of the digits of the ICCID.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.length]
+    }
+
+    /// Retrieve the major industry identifier, which is `89` for telecommunications (the first two digits of the ICCID).
+    pub fn major_industry_identifier(&self) -> &[u8; 2] {
+        self.digits[0..2].try_into().unwrap()
+    }
+
+    /// Retrieve the E.164 country code of the issuer, which is one to three digits long (starting at digit 3 of the ICCID).
+    pub fn country_code(&self) -> &[u8] {
+        let length = country_code_length(&self.digits[2..]);
+        &self.digits[2..2 + length]
+    }
+
+    /// Retrieve the issuer identifier, which is the rest of the seven-digit issuer identification number after
+    /// the major industry identifier and the country code.
+    pub fn issuer_identifier(&self) -> &[u8] {
+        &self.digits[2 + self.country_code().len()..7]
+    }
+
+    /// Retrieve the issuer identification number (IIN), which is the major industry identifier, the country code,
+    /// and the issuer identifier (the first seven digits of the ICCID).
+    pub fn issuer_identification_number(&self) -> &[u8; 7] {
+        self.digits[0..7].try_into().unwrap()
+    }
+
+    /// Retrieve the individual account number, which is every digit between the IIN and the check digit.
+    pub fn account_number(&self) -> &[u8] {
+        &self.digits[7..self.length - 1]
+    }
+
+    /// Retrieve the check digit, which is used for validation using Luhn's algorithm (the last digit of the ICCID).
+    pub fn check_digit(&self) -> u8 {
+        self.digits[self.length - 1]
+    }
+
+    /// Check if the ICCID is numerically valid. This does *not* mean that the ICCID belongs to an issued SIM card or profile.
+    pub fn is_valid(&self) -> bool {
+        luhn_checksum(&self.digits[..self.length - 1]) == self.check_digit()
+    }
+}
+
+impl Eid {
+    /// Retrieve the major industry identifier, which is `89` for telecommunications (the first two digits of the EID).
+    pub fn major_industry_identifier(&self) -> &[u8; 2] {
+        self.digits[0..2].try_into().unwrap()
+    }
+
+    /// Retrieve the country code of the eUICC manufacturer (EUM), zero-padded to three digits (digits 3 through 5 of the EID).
+    pub fn country_code(&self) -> &[u8; 3] {
+        self.digits[2..5].try_into().unwrap()
+    }
+
+    /// Retrieve the issuer identifier, which is assigned to the EUM (digits 6 through 8 of the EID).
+    pub fn issuer_identifier(&self) -> &[u8; 3] {
+        self.digits[5..8].try_into().unwrap()
+    }
+
+    /// Retrieve the EUM-specific version information for the eUICC platform and operating system (digits 9 through 13 of the EID).
+    pub fn version_information(&self) -> &[u8; 5] {
+        self.digits[8..13].try_into().unwrap()
+    }
+
+    /// Retrieve the additional EUM-specific information, such as the production site (digits 14 through 18 of the EID).
+    pub fn additional_issuer_information(&self) -> &[u8; 5] {
+        self.digits[13..18].try_into().unwrap()
+    }
+
+    /// Retrieve the individual identification number of the eUICC, which is assigned by the EUM (digits 19 through 30 of the EID).
+    pub fn individual_identification_number(&self) -> &[u8; 12] {
+        self.digits[18..30].try_into().unwrap()
+    }
+
+    /// Retrieve the check digits, which are used for validation using ISO 7064 MOD 97-10 (digits 31 and 32 of the EID).
+    pub fn check_digits(&self) -> &[u8; 2] {
+        self.digits[30..32].try_into().unwrap()
+    }
+
+    /// Check if the EID is numerically valid, meaning that the whole number is congruent to 1 modulo 97.
+    /// This does *not* mean that the EID belongs to a real eUICC.
+    pub fn is_valid(&self) -> bool {
+        self.digits.iter().fold(0u32, |remainder, digit| {
+            (remainder * 10 + *digit as u32) % 97
+        }) == 1
+    }
+}
+
+impl FromStr for Iccid {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0u8; 20];
+        let mut length = 0;
+        for c in s.chars() {
+            let Some(digit) = c.to_digit(10) else {
+                return Err(ImeiWrapperError::CannotParseDigits);
+            };
+
+            if length == digits.len() {
+                return Err(ImeiWrapperError::IncorrectLength);
+            }
+
+            digits[length] = digit as u8;
+            length += 1;
+        }
+
+        if length < 19 {
+            return Err(ImeiWrapperError::IncorrectLength);
+        }
+
+        let iccid = Self { digits, length };
+        if !iccid.is_valid() {
+            return Err(ImeiWrapperError::ChecksumDoesNotMatch);
+        }
+
+        Ok(iccid)
+    }
+}
+
+impl FromStr for Eid {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let eid = Self {
+            digits: string_to_digits(s)?,
+        };
+        if !eid.is_valid() {
+            return Err(ImeiWrapperError::ChecksumDoesNotMatch);
+        }
+
+        Ok(eid)
+    }
+}
+
+impl FromStr for EsimIdentifier {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 32 {
+            Ok(EsimIdentifier::Eid(Eid::from_str(s)?))
+        } else {
+            Ok(EsimIdentifier::Iccid(Iccid::from_str(s)?))
+        }
+    }
+}
+
+impl TryFrom<&str> for Iccid {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&str> for Eid {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&str> for EsimIdentifier {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl From<Iccid> for EsimIdentifier {
+    fn from(iccid: Iccid) -> Self {
+        EsimIdentifier::Iccid(iccid)
+    }
+}
+
+impl From<Eid> for EsimIdentifier {
+    fn from(eid: Eid) -> Self {
+        EsimIdentifier::Eid(eid)
+    }
+}
+
+impl Display for Iccid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits().iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Display for Eid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits.iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Display for EsimIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EsimIdentifier::Iccid(iccid) => iccid.fmt(f),
+            EsimIdentifier::Eid(eid) => eid.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Iccid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_digits(self.digits(), serializer)
+    }
+}
+
+impl Serialize for Eid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_digits(&self.digits, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Iccid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an ICCID of 19 or 20 digits"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Eid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an EID of 32 digits"))
+    }
+}
+
+/// Deserializes an identifier from a string using its `FromStr` implementation.
+pub(crate) struct FromStrVisitor<T> {
+    expecting: &'static str,
+    identifier: core::marker::PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    pub(crate) fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            identifier: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr<Err = ImeiWrapperError>> Visitor<'_> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str(v).map_err(E::custom)
+    }
+}
+
+/// Serialize digits as a string without allocating.
+pub(crate) fn serialize_digits<S: Serializer>(
+    digits: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut characters = [0u8; 32];
+    for (character, digit) in characters.iter_mut().zip(digits) {
+        *character = b'0' + digit;
+    }
+
+    serializer.serialize_str(core::str::from_utf8(&characters[..digits.len()]).unwrap())
+}
+
+/// Get the length of the E.164 country code at the start of the digits, based on the ITU-T assignment of
+/// one-digit codes to zones 1 and 7 and of two- or three-digit codes within each of the other zones.
+pub(crate) fn country_code_length(digits: &[u8]) -> usize {
+    match (digits[0], digits[1]) {
+        (1 | 7, _) => 1,
+        (2, 0 | 7)
+        | (3, 0..=4 | 6 | 9)
+        | (4, 0 | 1 | 3..=9)
+        | (5, 1..=8)
+        | (6, 0..=6)
+        | (8, 1 | 2 | 4 | 6)
+        | (9, 0..=5 | 8) => 2,
+        _ => 3,
+    }
+}
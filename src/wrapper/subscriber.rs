@@ -0,0 +1,249 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::sim::{country_code_length, serialize_digits, FromStrVisitor};
+use crate::mcc_mnc::{digits_match, mnc_length, Country, Operator, COUNTRIES, OPERATORS};
+use crate::wrapper::ImeiWrapperError;
+
+/// An IMSI (international mobile subscriber identity), the number stored on a SIM card which identifies a
+/// subscription, represented using an array of digits to prevent leading-zero truncation.
+///
+/// An IMSI is up to 15 digits long: a three-digit mobile country code (MCC), a two- or three-digit mobile
+/// network code (MNC), and the mobile subscription identification number (MSIN).
+/// It is serialized as a string of digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Imsi {
+    digits: [u8; 15],
+    length: usize,
+    mnc_length: usize,
+}
+
+/// An MSISDN (mobile station international subscriber directory number), the phone number of a subscription
+/// in E.164 format, represented using an array of digits to prevent leading-zero truncation.
+///
+/// An MSISDN is up to 15 digits long: a one- to three-digit country calling code followed by the national number.
+/// It is displayed and serialized with a leading `+`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Msisdn {
+    digits: [u8; 15],
+    length: usize,
+}
+
+impl Imsi {
+    /// Parse an IMSI with an MNC of a known length, for networks which are not in the embedded operator table.
+    /// The MNC length must be 2 or 3.
+    pub fn with_mnc_length(s: &str, mnc_length: usize) -> Result<Self, ImeiWrapperError> {
+        if !matches!(mnc_length, 2 | 3) {
+            return Err(ImeiWrapperError::ValueOutOfRange);
+        }
+
+        let (digits, length) = variable_string_to_digits(s, 6)?;
+        Ok(Self {
+            digits,
+            length,
+            mnc_length,
+        })
+    }
+
+    /// Retrieve all of the digits of the IMSI.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.length]
+    }
+
+    /// Retrieve the mobile country code (MCC), which identifies the country of the operator (the first three digits of the IMSI).
+    pub fn mobile_country_code(&self) -> &[u8; 3] {
+        self.digits[0..3].try_into().unwrap()
+    }
+
+    /// Retrieve the mobile network code (MNC), which identifies the operator within its country (the two or three
+    /// digits after the MCC).
+    pub fn mobile_network_code(&self) -> &[u8] {
+        &self.digits[3..3 + self.mnc_length]
+    }
+
+    /// Retrieve the mobile subscription identification number (MSIN), which identifies the subscription within
+    /// the operator (every digit after the MNC).
+    pub fn msin(&self) -> &[u8] {
+        &self.digits[3 + self.mnc_length..self.length]
+    }
+
+    /// Look up the country of the operator in the embedded country table.
+    pub fn country(&self) -> Option<&'static Country> {
+        COUNTRIES.iter().find(|country| {
+            country
+                .mobile_country_codes
+                .iter()
+                .any(|mcc| digits_match(self.mobile_country_code(), mcc))
+        })
+    }
+
+    /// Look up the operator which issued the SIM card in the embedded operator table.
+    pub fn operator(&self) -> Option<&'static Operator> {
+        OPERATORS.iter().find(|operator| {
+            digits_match(self.mobile_country_code(), operator.mobile_country_code)
+                && digits_match(self.mobile_network_code(), operator.mobile_network_code)
+        })
+    }
+}
+
+impl Msisdn {
+    /// Retrieve all of the digits of the MSISDN, without the leading `+`.
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.length]
+    }
+
+    /// Retrieve the country calling code, which is one to three digits long.
+    pub fn country_code(&self) -> &[u8] {
+        &self.digits[..country_code_length(&self.digits)]
+    }
+
+    /// Retrieve the national number, which is every digit after the country calling code.
+    pub fn national_number(&self) -> &[u8] {
+        &self.digits[self.country_code().len()..self.length]
+    }
+
+    /// Look up the countries which use the country calling code in the embedded country table.
+    /// There may be more than one, since countries in the North American Numbering Plan share the `+1`
+    /// calling code and Kazakhstan shares the `+7` calling code with Russia.
+    ///
+    /// The operator of a number cannot be determined offline because of number portability; use a
+    /// carrier lookup instead.
+    pub fn countries(&self) -> impl Iterator<Item = &'static Country> + '_ {
+        let calling_code = self.country_code();
+        COUNTRIES
+            .iter()
+            .filter(move |country| digits_match(calling_code, country.calling_code))
+    }
+
+    /// Parse an MSISDN which may contain separators, such as `+1 (555) 123-4567`.
+    /// Whitespace, dashes, dots, and parentheses are ignored; any other non-numeric character is rejected.
+    pub fn parse_lenient(s: &str) -> Result<Self, ImeiWrapperError> {
+        let s = s.trim_start();
+        let s = s.strip_prefix('+').unwrap_or(s);
+        Self::from_chars(
+            s.chars()
+                .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.' | '(' | ')')),
+        )
+    }
+
+    fn from_chars(chars: impl Iterator<Item = char>) -> Result<Self, ImeiWrapperError> {
+        let (digits, length) = variable_chars_to_digits(chars, 7)?;
+        // * Country calling codes never start with zero
+        if digits[0] == 0 {
+            return Err(ImeiWrapperError::ValueOutOfRange);
+        }
+
+        Ok(Self { digits, length })
+    }
+}
+
+impl FromStr for Imsi {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, length) = variable_string_to_digits(s, 6)?;
+        Ok(Self {
+            digits,
+            length,
+            mnc_length: mnc_length(&digits),
+        })
+    }
+}
+
+impl FromStr for Msisdn {
+    type Err = ImeiWrapperError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_chars(s.strip_prefix('+').unwrap_or(s).chars())
+    }
+}
+
+impl TryFrom<&str> for Imsi {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<&str> for Msisdn {
+    type Error = ImeiWrapperError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl Display for Imsi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.digits().iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Display for Msisdn {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("+")?;
+        self.digits().iter().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+impl Serialize for Imsi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_digits(self.digits(), serializer)
+    }
+}
+
+impl Serialize for Msisdn {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut characters = [b'+'; 16];
+        for (character, digit) in characters[1..].iter_mut().zip(self.digits()) {
+            *character = b'0' + digit;
+        }
+
+        serializer.serialize_str(core::str::from_utf8(&characters[..=self.length]).unwrap())
+    }
+}
+
+impl<'de> Deserialize<'de> for Imsi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an IMSI of up to 15 digits"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Msisdn {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("an E.164 phone number"))
+    }
+}
+
+fn variable_string_to_digits(
+    s: &str,
+    minimum_length: usize,
+) -> Result<([u8; 15], usize), ImeiWrapperError> {
+    variable_chars_to_digits(s.chars(), minimum_length)
+}
+
+/// Convert between `minimum_length` and 15 characters to digits, returning the digits and their count.
+fn variable_chars_to_digits(
+    chars: impl Iterator<Item = char>,
+    minimum_length: usize,
+) -> Result<([u8; 15], usize), ImeiWrapperError> {
+    let mut digits = [0u8; 15];
+    let mut length = 0;
+    for c in chars {
+        let Some(digit) = c.to_digit(10) else {
+            return Err(ImeiWrapperError::CannotParseDigits);
+        };
+
+        if length == digits.len() {
+            return Err(ImeiWrapperError::IncorrectLength);
+        }
+
+        digits[length] = digit as u8;
+        length += 1;
+    }
+
+    if length < minimum_length {
+        return Err(ImeiWrapperError::IncorrectLength);
+    }
+
+    Ok((digits, length))
+}
@@ -0,0 +1,85 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{parse_api_date, ApiFlag, ApiXiaomiInfo, ApiXiaomiMiLockInfo};
+use crate::wrapper::Imei;
+
+/// The information about a Xiaomi device returned by the Xiaomi info check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XiaomiInfo {
+    pub imei: Imei,
+    /// The marketing name of the model, such as `"Redmi Note 10 Pro"`.
+    pub model: Option<String>,
+    /// The model number, such as `"M2101K6G"`.
+    pub model_code: Option<String>,
+    /// The region the firmware was made for, such as `"Global"`, `"China"`, `"India"`, or `"EEA"`.
+    pub region: Option<String>,
+    pub sold_country: Option<String>,
+    pub activated: Option<bool>,
+    pub activation_date: Option<NaiveDate>,
+    pub manufacture_date: Option<NaiveDate>,
+    pub warranty_end: Option<NaiveDate>,
+}
+
+/// Whether a Xiaomi device is locked to a Mi Account. While it is locked, the device cannot be set up by
+/// anyone else without the owner's Mi Account, which is the Xiaomi equivalent of Activation Lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MiLockState {
+    Locked,
+    Unlocked,
+    /// The service did not report the status, or reported it in a way which is not recognized.
+    Unknown,
+}
+
+/// The Mi Account (Mi Cloud) lock status of a Xiaomi device.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XiaomiMiLockStatus {
+    pub imei: Imei,
+    pub model: Option<String>,
+    pub mi_account_lock: MiLockState,
+    /// Whether the owner has marked the device as lost, if the service reported it.
+    pub lost_mode: Option<bool>,
+}
+
+impl XiaomiInfo {
+    pub(crate) fn from_api(imei: Imei, info: ApiXiaomiInfo) -> Self {
+        Self {
+            imei,
+            model: info.model,
+            model_code: info.model_code,
+            region: info.region,
+            sold_country: info.sold_country,
+            activated: info.activation_status.as_ref().and_then(ApiFlag::value),
+            activation_date: parse_api_date(info.activation_date.as_deref()),
+            manufacture_date: parse_api_date(info.manufacture_date.as_deref()),
+            warranty_end: parse_api_date(info.warranty_end.as_deref()),
+        }
+    }
+
+    /// Check whether the device is covered at the given time, including the last day of coverage. Devices
+    /// without a known end of coverage are not considered covered.
+    pub fn is_in_warranty(&self, at: DateTime<Utc>) -> bool {
+        self.warranty_end.is_some_and(|end| at.date_naive() <= end)
+    }
+}
+
+impl XiaomiMiLockStatus {
+    pub(crate) fn from_api(imei: Imei, info: ApiXiaomiMiLockInfo) -> Self {
+        Self {
+            imei,
+            model: info.model,
+            mi_account_lock: match info.mi_lock_status.as_ref().and_then(ApiFlag::value) {
+                Some(true) => MiLockState::Locked,
+                Some(false) => MiLockState::Unlocked,
+                None => MiLockState::Unknown,
+            },
+            lost_mode: info.lost_mode.as_ref().and_then(ApiFlag::value),
+        }
+    }
+
+    /// Check whether the device is locked to a Mi Account. Devices with an unknown status are not considered
+    /// locked, so check [`XiaomiMiLockStatus::mi_account_lock`] directly to treat them as locked.
+    pub fn is_locked(&self) -> bool {
+        self.mi_account_lock == MiLockState::Locked
+    }
+}
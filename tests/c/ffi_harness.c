@@ -0,0 +1,162 @@
+// C test harness for the `ffi` module, linked into and run by the `ffi` integration test.
+
+#include <stdio.h>
+#include <string.h>
+
+#include "imei_info.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                       \
+  do {                                                                         \
+    if (!(condition)) {                                                        \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,        \
+              #condition);                                                     \
+      failures++;                                                              \
+    }                                                                          \
+  } while (0)
+
+#define CHECK_STATUS(call, expected) CHECK((call) == (expected))
+
+static void test_imei(void) {
+  const char *valid = "356741089728686";
+  const char *bad_checksum = "356741089728687";
+  ImeiInfoImei imei;
+  char buffer[32];
+
+  CHECK_STATUS(imei_info_imei_validate(valid, strlen(valid)),
+               IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_imei_validate(bad_checksum, strlen(bad_checksum)),
+               IMEI_INFO_STATUS_CHECKSUM_DOES_NOT_MATCH);
+  CHECK_STATUS(imei_info_imei_validate("35674108972868", 14),
+               IMEI_INFO_STATUS_INCORRECT_LENGTH);
+  CHECK_STATUS(imei_info_imei_validate("3567410897286a6", 15),
+               IMEI_INFO_STATUS_CANNOT_PARSE_DIGITS);
+  CHECK_STATUS(imei_info_imei_validate(NULL, 0), IMEI_INFO_STATUS_NULL_POINTER);
+
+  // The input length is explicit, so trailing bytes past `len` are ignored.
+  CHECK_STATUS(imei_info_imei_validate("356741089728686XYZ", 15),
+               IMEI_INFO_STATUS_OK);
+
+  CHECK_STATUS(imei_info_imei_parse(valid, strlen(valid), &imei),
+               IMEI_INFO_STATUS_OK);
+  CHECK(imei.digits[0] == 3 && imei.digits[1] == 5 && imei.digits[14] == 6);
+  CHECK_STATUS(imei_info_imei_parse(valid, strlen(valid), NULL),
+               IMEI_INFO_STATUS_NULL_POINTER);
+
+  CHECK_STATUS(imei_info_imei_format(&imei, buffer, sizeof(buffer)),
+               IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, valid) == 0);
+  CHECK_STATUS(imei_info_imei_format(&imei, buffer, 15),
+               IMEI_INFO_STATUS_BUFFER_TOO_SMALL);
+
+  // The digits are writable from C, so they are range checked before formatting.
+  ImeiInfoImei out_of_range = imei;
+  out_of_range.digits[3] = 10;
+  buffer[0] = 'X';
+  CHECK_STATUS(imei_info_imei_format(&out_of_range, buffer, sizeof(buffer)),
+               IMEI_INFO_STATUS_VALUE_OUT_OF_RANGE);
+  CHECK(buffer[0] == 'X');
+
+  const char *grouped = " 35-674108-972868-6 ";
+  CHECK_STATUS(imei_info_imei_parse(grouped, strlen(grouped), &imei),
+               IMEI_INFO_STATUS_CANNOT_PARSE_DIGITS);
+  CHECK_STATUS(imei_info_imei_parse_lenient(grouped, strlen(grouped), &imei),
+               IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(
+      imei_info_imei_normalize(grouped, strlen(grouped), buffer, sizeof(buffer)),
+      IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, valid) == 0);
+}
+
+static void test_imeisv(void) {
+  const char *valid = "3567410897286801";
+  ImeiInfoImeiSv imeisv;
+  ImeiInfoImei imei;
+  char buffer[32];
+
+  CHECK_STATUS(imei_info_imeisv_validate(valid, strlen(valid)),
+               IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_imeisv_validate(valid, 15),
+               IMEI_INFO_STATUS_INCORRECT_LENGTH);
+  CHECK_STATUS(imei_info_imeisv_parse(valid, strlen(valid), &imeisv),
+               IMEI_INFO_STATUS_OK);
+  CHECK(imeisv.digits[14] == 0 && imeisv.digits[15] == 1);
+
+  CHECK_STATUS(imei_info_imeisv_to_imei(&imeisv, &imei), IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_imei_format(&imei, buffer, sizeof(buffer)),
+               IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, "356741089728686") == 0);
+
+  const char *grouped = "35 674108 972868 01";
+  CHECK_STATUS(imei_info_imeisv_normalize(grouped, strlen(grouped), buffer,
+                                          sizeof(buffer)),
+               IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, valid) == 0);
+}
+
+static void test_tac(void) {
+  const char *imei_string = "351725105350612";
+  ImeiInfoImei imei;
+  ImeiInfoTac tac;
+  char buffer[9];
+
+  CHECK_STATUS(imei_info_tac_validate("35172510", 8), IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_tac_validate("3517251", 7),
+               IMEI_INFO_STATUS_INCORRECT_LENGTH);
+
+  CHECK_STATUS(imei_info_imei_parse(imei_string, strlen(imei_string), &imei),
+               IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_imei_to_tac(&imei, &tac), IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_tac_format(&tac, buffer, sizeof(buffer)),
+               IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, "35172510") == 0);
+
+  CHECK_STATUS(imei_info_tac_normalize("35-172510", 9, buffer, sizeof(buffer)),
+               IMEI_INFO_STATUS_OK);
+  CHECK(strcmp(buffer, "35172510") == 0);
+  CHECK_STATUS(imei_info_tac_parse_lenient("35.172510", 9, &tac),
+               IMEI_INFO_STATUS_OK);
+  CHECK_STATUS(imei_info_tac_parse("35.172510", 9, &tac),
+               IMEI_INFO_STATUS_CANNOT_PARSE_DIGITS);
+}
+
+static void test_check_digits(void) {
+  const uint8_t digits[] = {3, 5, 6, 7, 4, 1, 0, 8, 9, 7, 2, 8, 6, 8};
+  const uint8_t bad_digits[] = {3, 5, 10};
+  uint8_t check_digit = 255;
+
+  CHECK_STATUS(
+      imei_info_luhn_check_digit(digits, sizeof(digits), &check_digit),
+      IMEI_INFO_STATUS_OK);
+  CHECK(check_digit == 6);
+  CHECK_STATUS(imei_info_luhn_check_digit(bad_digits, sizeof(bad_digits),
+                                          &check_digit),
+               IMEI_INFO_STATUS_VALUE_OUT_OF_RANGE);
+
+  check_digit = 255;
+  CHECK_STATUS(
+      imei_info_imei_compute_check_digit("35674108972868", 14, &check_digit),
+      IMEI_INFO_STATUS_OK);
+  CHECK(check_digit == 6);
+  CHECK_STATUS(
+      imei_info_imei_compute_check_digit("356741089728686", 15, &check_digit),
+      IMEI_INFO_STATUS_INCORRECT_LENGTH);
+}
+
+static void test_status_messages(void) {
+  CHECK(strcmp(imei_info_status_message(IMEI_INFO_STATUS_OK), "success") == 0);
+  CHECK(strlen(imei_info_status_message(
+            IMEI_INFO_STATUS_CHECKSUM_DOES_NOT_MATCH)) > 0);
+  CHECK(strcmp(imei_info_status_message(99), "unknown status code") == 0);
+  CHECK(strcmp(imei_info_status_message(-1), "unknown status code") == 0);
+}
+
+int imei_info_ffi_harness(void) {
+  test_imei();
+  test_imeisv();
+  test_tac();
+  test_check_digits();
+  test_status_messages();
+  return failures;
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "ffi")]
+
+use std::ffi::c_int;
+
+#[link(name = "imei_info_ffi_harness", kind = "static")]
+extern "C" {
+    fn imei_info_ffi_harness() -> c_int;
+}
+
+#[test]
+fn c_harness() {
+    // * The harness only calls into the library through the C ABI, so reference the module to make sure it is linked
+    let _ = imei_info::ffi::imei_info_status_message;
+    assert_eq!(unsafe { imei_info_ffi_harness() }, 0);
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let checked_in = include_str!("../include/imei_info.h");
+    let generated = include_str!(env!("IMEI_INFO_GENERATED_HEADER"));
+    assert!(
+        checked_in == generated,
+        "`include/imei_info.h` is out of date; copy it from {}",
+        env!("IMEI_INFO_GENERATED_HEADER")
+    );
+}